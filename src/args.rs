@@ -1,44 +1,266 @@
-use clap::Parser;
+use crate::common::err::SystemError;
+use crate::common::fs::file_reader::Reader;
+use crate::git::cleanup::Cleanup;
+
+use clap::{Parser, Subcommand};
+use std::io::Read;
+use std::path::Path;
+
+const STDIN: &str = "-";
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Parser, Debug)]
 #[command(version, about, long_about = None)]
 pub struct Args {
+	#[command(subcommand)]
+	pub command: Option<Command>,
+
 	/// CSV file containing a list of authors (alias,name,email)
-	#[arg(short, long)]
+	#[arg(short, long, global = true)]
 	pub file: Option<String>,
 
-	/// List of comma separated author aliases
-	#[arg(short, long)]
+	/// List of comma separated author aliases, prefix with a role to credit them otherwise (e.g. r:bb), - to read from stdin
+	#[arg(short, long, global = true)]
 	pub list: Option<String>,
 
+	/// List of comma separated aliases of the reviewers
+	#[arg(long, global = true)]
+	pub reviewed_by: Option<String>,
+
+	/// Add a trailer to the commit message (repeatable)
+	#[arg(long, value_name = "KEY=VALUE", value_parser = parse_trailer, global = true)]
+	pub trailer: Vec<String>,
+
+	/// Add a Signed-off-by trailer for the committer
+	#[arg(long, default_value = "false", global = true)]
+	pub signoff: bool,
+
 	/// Use all available authors
-	#[arg(short, long, conflicts_with("list"), default_value = "false")]
+	#[arg(short, long, conflicts_with("list"), default_value = "false", global = true)]
 	pub all: bool,
 
 	/// Specify commit message
-	#[arg(short, long, conflicts_with("editor"), conflicts_with("pre_populate"))]
+	#[arg(short, long, conflicts_with("editor"), conflicts_with("pre_populate"), global = true)]
 	pub message: Option<String>,
 
+	/// Read the commit message from a file, - to read from stdin
+	#[arg(
+		short = 'F',
+		long,
+		value_name = "FILE",
+		conflicts_with("message"),
+		conflicts_with("editor"),
+		conflicts_with("pre_populate"),
+		conflicts_with("template"),
+		conflicts_with("conventional"),
+		global = true
+	)]
+	pub file_message: Option<String>,
+
+	/// Start the message from a named template, picked with fzf when no name is given
+	#[arg(
+		long,
+		value_name = "NAME",
+		num_args = 0..=1,
+		default_missing_value = "",
+		conflicts_with("message"),
+		conflicts_with("pre_populate"),
+		global = true
+	)]
+	pub template: Option<String>,
+
+	/// Build a Conventional Commits message step by step
+	#[arg(
+		long,
+		default_value = "false",
+		conflicts_with("message"),
+		conflicts_with("pre_populate"),
+		conflicts_with("template"),
+		global = true
+	)]
+	pub conventional: bool,
+
 	/// Open default editor for commit message
-	#[arg(short, long, default_value = "false")]
+	#[arg(short, long, default_value = "false", global = true)]
 	pub editor: bool,
 
-	/// Pre-populate prompt/editor with (first line of) last commit message
-	#[arg(short, long, default_value = "false")]
+	/// Show the staged diff in the editor [default: commit.verbose]
+	#[arg(short, long, default_value = "false", global = true)]
+	pub verbose: bool,
+
+	/// Pre-populate prompt/editor with last commit message
+	#[arg(short, long, default_value = "false", global = true)]
 	pub pre_populate: bool,
 
+	/// Pre-populate prompt/editor with the message of the given commit
+	#[arg(
+		long,
+		value_name = "REV",
+		conflicts_with("message"),
+		conflicts_with("file_message"),
+		conflicts_with("template"),
+		conflicts_with("conventional"),
+		global = true
+	)]
+	pub reuse: Option<String>,
+
+	/// Commit as the author with this alias, crediting the machine owner as co-author instead
+	#[arg(long, global = true)]
+	pub driver: Option<String>,
+
 	/// Sort authors signatures when adding to commit message
-	#[arg(short, long, default_value = "false")]
+	#[arg(short, long, default_value = "false", global = true)]
 	pub sort: bool,
 
 	/// Amend last commit, both message and authors will be overwritten
-	#[arg(long, default_value = "false")]
+	#[arg(long, default_value = "false", conflicts_with("template"))]
 	pub amend: bool,
 
+	/// Commit without the preview asking to confirm
+	#[arg(short, long, default_value = "false", global = true)]
+	pub yes: bool,
+
+	/// How to clean up the commit message (strip, whitespace, verbatim, scissors, default) [default: commit.cleanup]
+	#[arg(long, value_name = "MODE", value_parser = parse_cleanup, global = true)]
+	pub cleanup: Option<Cleanup>,
+
 	/// Use fzf for author selection
-	#[arg(long, default_value = "false", conflicts_with("list"), conflicts_with("all"))]
+	#[arg(
+		long,
+		default_value = "false",
+		conflicts_with("list"),
+		conflicts_with("all"),
+		global = true
+	)]
 	pub fzf: bool,
 	// FIXME: if multiple authors have the same alias, selecting one with fzf will add them all as co-authors
 	// TODO: instead of integrated fzf functionality, eval if atty crate is a better fit
 }
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+	/// Share the co-authors of a mob session through the git remote
+	Mob {
+		/// Remote used to share the session [default: origin]
+		#[arg(long, global = true)]
+		remote: Option<String>,
+
+		#[command(subcommand)]
+		action: MobAction,
+	},
+	/// Commit everything as work in progress and push it for the next driver
+	Handoff(WipArgs),
+	/// Fetch and check out the work in progress pushed by the previous driver
+	Take(WipArgs),
+	/// Squash the work in progress into a single commit on the base branch
+	Done(WipArgs),
+	/// Pair rotation helpers based on the co-authorship history
+	Pairs {
+		#[command(subcommand)]
+		action: PairsAction,
+	},
+}
+
+#[derive(clap::Args, Debug)]
+pub struct WipArgs {
+	/// Remote used to share the work in progress [default: origin]
+	#[arg(long)]
+	pub remote: Option<String>,
+
+	/// Branch used to share the work in progress [default: mob/<current branch>]
+	#[arg(long)]
+	pub wip_branch: Option<String>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum MobAction {
+	/// Start a session with the selected authors and push it
+	Start,
+	/// Fetch and adopt the session pushed by a teammate
+	Join,
+	/// End the session, both locally and on the remote
+	Stop,
+	/// Count down the current driver's turn and rotate to the next one
+	Timer {
+		/// Length of the turn, a day at most
+		#[arg(value_parser = clap::value_parser!(u64).range(1..=24 * 60))]
+		minutes: u64,
+
+		/// Ring the terminal bell when time is up
+		#[arg(long, default_value = "false")]
+		bell: bool,
+
+		/// Command to run with the next driver announcement when time is up (e.g. notify-send)
+		#[arg(long)]
+		notify: Option<String>,
+	},
+}
+
+/// Takes `key=value` as well as git's `key: value`, and gives the trailer line
+fn parse_trailer(arg: &str) -> Result<String, String> {
+	let (key, value) = arg
+		.split_once(['=', ':'])
+		.ok_or_else(|| format!("expected KEY=VALUE, got {arg}"))?;
+	let (key, value) = (key.trim(), value.trim());
+	if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+		return Err(format!("invalid trailer key {key:?}"));
+	}
+	Ok(format!("{key}: {value}"))
+}
+
+fn parse_cleanup(arg: &str) -> Result<Cleanup, String> {
+	Cleanup::parse(arg).ok_or_else(|| format!("unknown cleanup mode {arg}"))
+}
+
+impl Args {
+	pub fn selects_authors(&self) -> bool {
+		self.list.is_some() || self.all || self.fzf
+	}
+
+	/// Takes the message given with `--file-message` and the aliases given with `--list -` as if passed inline.
+	/// Stdin is only given when it isn't a terminal, and can only be read once
+	pub fn with_inputs(mut self, reader: &impl Reader, stdin: Option<&mut dyn Read>) -> crate::Result<Self> {
+		let from_stdin = |arg: &Option<String>| arg.as_deref() == Some(STDIN);
+		if from_stdin(&self.file_message) && from_stdin(&self.list) {
+			return Err(SystemError::Read("stdin for both --file-message and --list".to_string()).into());
+		}
+		let input = if from_stdin(&self.file_message) || from_stdin(&self.list) {
+			let stdin = stdin.ok_or_else(|| SystemError::Read("stdin, it is a terminal".to_string()))?;
+			let mut input = String::new();
+			stdin
+				.read_to_string(&mut input)
+				.map_err(|e| SystemError::Read(format!("stdin: {e}")))?;
+			input
+		} else {
+			String::new()
+		};
+
+		if from_stdin(&self.list) {
+			let aliases: Vec<&str> = input
+				.split(|c: char| c == ',' || c.is_whitespace())
+				.filter(|alias| !alias.is_empty())
+				.collect();
+			self.list = Some(aliases.join(","));
+		}
+		match self.file_message.take() {
+			Some(path) if path == STDIN => self.message = Some(input),
+			Some(path) => self.message = Some(reader.read(Path::new(&path))?),
+			None => {}
+		}
+		Ok(self)
+	}
+}
+
+#[derive(Subcommand, Debug)]
+pub enum PairsAction {
+	/// Suggest pairs among the selected authors, favouring those who haven't paired recently
+	Suggest {
+		/// Days of history to look back on
+		#[arg(long, default_value = "30")]
+		days: u64,
+
+		/// Print the suggestion and the pairing matrix as JSON
+		#[arg(long, default_value = "false")]
+		json: bool,
+	},
+}
@@ -0,0 +1,132 @@
+use crate::args::{Args, Command};
+use crate::common::err::SystemError;
+use crate::common::fs::file_reader::MockReader;
+use crate::error::{assert_error_contains_msg, assert_error_type};
+use crate::Result;
+use clap::{CommandFactory, Parser};
+use std::path::Path;
+
+#[test]
+fn be_consistently_defined() {
+	Args::command().debug_assert();
+}
+
+#[test]
+fn accept_commit_options_after_a_command() {
+	let args = Args::try_parse_from(["co-author", "done", "-m", "a message", "-l", "a,b"]);
+
+	assert!(matches!(args, Ok(args)
+		if matches!(args.command, Some(Command::Done(_)))
+			&& args.message == Some("a message".to_string())
+			&& args.list == Some("a,b".to_string())));
+}
+
+#[test]
+fn accept_sorting_after_a_command() {
+	let done = Args::try_parse_from(["co-author", "done", "--sort"]);
+	let handoff = Args::try_parse_from(["co-author", "handoff", "-s"]);
+
+	assert!(matches!(done, Ok(args) if args.sort));
+	assert!(matches!(handoff, Ok(args) if args.sort));
+}
+
+#[test]
+fn accept_repeated_trailers_in_both_notations() {
+	let args = Args::try_parse_from([
+		"co-author",
+		"--trailer",
+		"Reviewed-by=Bob <bob@x>",
+		"--trailer",
+		"Bug: 42",
+	]);
+
+	assert!(matches!(args, Ok(args) if args.trailer == ["Reviewed-by: Bob <bob@x>", "Bug: 42"]));
+}
+
+#[test]
+fn reject_trailers_without_a_valid_key() {
+	assert!(Args::try_parse_from(["co-author", "--trailer", "no separator"]).is_err());
+	assert!(Args::try_parse_from(["co-author", "--trailer", "not a key=value"]).is_err());
+}
+
+#[test]
+fn not_take_a_message_file_along_with_another_message() {
+	assert!(Args::try_parse_from(["co-author", "-F", "msg.txt", "-m", "a message"]).is_err());
+	assert!(Args::try_parse_from(["co-author", "-F", "msg.txt", "--editor"]).is_err());
+	assert!(Args::try_parse_from(["co-author", "-F", "msg.txt", "--pre-populate"]).is_err());
+}
+
+#[test]
+fn take_the_message_from_a_file() -> Result<()> {
+	let mut reader = MockReader::new();
+	reader
+		.expect_read()
+		.withf(|path| path == Path::new("msg.txt"))
+		.returning(|_| Ok("a subject\n\na body\n".to_string()));
+
+	let args = Args::parse_from(["co-author", "-F", "msg.txt"]).with_inputs(&reader, None)?;
+
+	assert_eq!(args.message, Some("a subject\n\na body\n".to_string()));
+	assert_eq!(args.file_message, None);
+	Ok(())
+}
+
+#[test]
+fn take_the_message_from_stdin() -> Result<()> {
+	let mut stdin = "a subject\n\na body\n".as_bytes();
+
+	let args = Args::parse_from(["co-author", "--file-message", "-", "-l", "a"])
+		.with_inputs(&MockReader::new(), Some(&mut stdin))?;
+
+	assert_eq!(args.message, Some("a subject\n\na body\n".to_string()));
+	assert_eq!(args.list, Some("a".to_string()));
+	Ok(())
+}
+
+#[test]
+fn take_the_aliases_from_stdin() -> Result<()> {
+	let mut stdin = "a,b\nc  r:d\n".as_bytes();
+
+	let args = Args::parse_from(["co-author", "-l", "-"]).with_inputs(&MockReader::new(), Some(&mut stdin))?;
+
+	assert_eq!(args.list, Some("a,b,c,r:d".to_string()));
+	Ok(())
+}
+
+#[test]
+fn not_read_stdin_from_a_terminal() {
+	let result = Args::parse_from(["co-author", "-l", "-"]).with_inputs(&MockReader::new(), None);
+
+	assert_error_type(&result, &SystemError::Read(String::new()));
+}
+
+#[test]
+fn not_read_stdin_twice() {
+	let mut stdin = "a".as_bytes();
+
+	let result =
+		Args::parse_from(["co-author", "-F", "-", "-l", "-"]).with_inputs(&MockReader::new(), Some(&mut stdin));
+
+	assert_error_type(&result, &SystemError::Read(String::new()));
+	assert_error_contains_msg(&result, "stdin for both --file-message and --list");
+}
+
+#[test]
+fn not_reuse_a_commit_message_along_with_another_message() {
+	assert!(Args::try_parse_from(["co-author", "--reuse", "HEAD~1", "-m", "a message"]).is_err());
+	assert!(Args::try_parse_from(["co-author", "--reuse", "HEAD~1", "-F", "msg.txt"]).is_err());
+	assert!(Args::try_parse_from(["co-author", "--reuse", "HEAD~1", "--editor"]).is_ok());
+}
+
+#[test]
+fn not_amend_from_a_template() {
+	assert!(Args::try_parse_from(["co-author", "--amend", "--template", "hotfix"]).is_err());
+	assert!(Args::try_parse_from(["co-author", "--template", "--amend"]).is_err());
+}
+
+#[test]
+fn keep_the_mob_timer_within_a_day() {
+	assert!(Args::try_parse_from(["co-author", "mob", "timer", "10"]).is_ok());
+	assert!(Args::try_parse_from(["co-author", "mob", "timer", "0"]).is_err());
+	assert!(Args::try_parse_from(["co-author", "mob", "timer", "18446744073709551615"]).is_err());
+}
@@ -1,12 +1,14 @@
+use super::template::SignatureTemplate;
 use crate::common::conf;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Author {
 	alias: String,
 	name: String,
 	email: String,
+	active: bool,
 }
 
 impl Author {
@@ -15,11 +17,21 @@ impl Author {
 			alias: String::from(alias),
 			name: String::from(name),
 			email: String::from(email),
+			active: true,
 		}
 	}
 
-	pub fn signature(&self) -> String {
-		format!("{}: {} <{}>", conf::co_author_prefix(), self.name, self.email)
+	pub fn deactivated(self) -> Self {
+		Self { active: false, ..self }
+	}
+
+	pub fn signature(&self, template: &SignatureTemplate) -> String {
+		self.signature_as(&conf::co_author_prefix(), template)
+	}
+
+	/// Same as a signature, under another trailer key such as `Reviewed-by`
+	pub fn signature_as(&self, key: &str, template: &SignatureTemplate) -> String {
+		format!("{key}: {}", template.render(self))
 	}
 
 	pub fn alias(&self) -> String {
@@ -30,6 +42,14 @@ impl Author {
 		self.name.clone()
 	}
 
+	pub fn email(&self) -> String {
+		self.email.clone()
+	}
+
+	pub fn is_active(&self) -> bool {
+		self.active
+	}
+
 	pub fn hash(&self) -> u64 {
 		let mut hasher = DefaultHasher::new();
 		let to_hash = format!("{}{}", self.alias, self.name);
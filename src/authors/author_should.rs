@@ -1,4 +1,7 @@
-use crate::{authors::author::Author, common::conf};
+use crate::{
+	authors::{author::Author, template::SignatureTemplate},
+	common::conf,
+};
 use parameterized::parameterized;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
@@ -10,7 +13,10 @@ fn present_a_co_author_compliant_signature() {
 	let author = Author::from("a", name, email);
 
 	let co_author_prefix = conf::co_author_prefix();
-	assert_eq!(author.signature(), format!("{co_author_prefix}: {name} <{email}>"));
+	assert_eq!(
+		author.signature(&SignatureTemplate::default()),
+		format!("{co_author_prefix}: {name} <{email}>")
+	);
 }
 
 #[test]
@@ -21,6 +27,14 @@ fn get_alias() {
 	assert_eq!(author.alias(), alias);
 }
 
+#[test]
+fn be_active_unless_deactivated() {
+	let author = Author::from("a", "alice", "alice@wonderland.not");
+
+	assert!(author.is_active());
+	assert!(!author.deactivated().is_active());
+}
+
 #[test]
 fn get_name() {
 	let name = "alice";
@@ -29,6 +43,14 @@ fn get_name() {
 	assert_eq!(author.name(), name);
 }
 
+#[test]
+fn get_email() {
+	let email = "alice@wonderland.not";
+	let author = Author::from("a", "alice", email);
+
+	assert_eq!(author.email(), email);
+}
+
 #[test]
 fn get_hash() {
 	let alias = "a";
@@ -64,3 +86,25 @@ fn hash_of(str: &str) -> u64 {
 	str.hash(&mut hasher);
 	hasher.finish()
 }
+
+#[test]
+fn present_a_signature_under_another_trailer_key() {
+	let author = Author::from("a", "alice", "alice@wonderland.not");
+
+	assert_eq!(
+		author.signature_as("Reviewed-by", &SignatureTemplate::default()),
+		"Reviewed-by: alice <alice@wonderland.not>"
+	);
+}
+
+#[test]
+fn present_a_signature_with_the_given_template() -> crate::Result<()> {
+	let author = Author::from("a", "alice", "alice@wonderland.not");
+	let template = SignatureTemplate::parse("{name} (@{alias}) <{email}>")?;
+
+	assert_eq!(
+		author.signature_as("Reviewed-by", &template),
+		"Reviewed-by: alice (@a) <alice@wonderland.not>"
+	);
+	Ok(())
+}
@@ -1,9 +1,21 @@
 use crate::authors::author::Author;
 
+const INACTIVE: &str = "inactive";
+
 pub fn to_author(line: &str) -> Option<Author> {
 	let fields: Vec<&str> = line.split(',').collect();
-	if fields.len() != 3 {
-		return None;
+	match fields[..] {
+		[alias, name, email] => Some(Author::from(alias, name, email)),
+		[alias, name, email, INACTIVE] => Some(Author::from(alias, name, email).deactivated()),
+		_ => None,
+	}
+}
+
+pub fn to_line(author: &Author) -> String {
+	let line = format!("{},{},{}", author.alias(), author.name(), author.email());
+	if author.is_active() {
+		line
+	} else {
+		format!("{line},{INACTIVE}")
 	}
-	Some(Author::from(fields[0], fields[1], fields[2]))
 }
@@ -21,3 +21,38 @@ fn not_map_from_invalid_csv_line() {
 
 	assert_eq!(no_author, None);
 }
+
+#[test]
+fn map_inactive_authors() {
+	let author_from_csv = mapper::to_author("a,alice,alice@wonderland.not,inactive");
+
+	assert_eq!(
+		author_from_csv,
+		Some(Author::from("a", "alice", "alice@wonderland.not").deactivated())
+	);
+}
+
+#[test]
+fn not_map_from_unknown_fourth_field() {
+	let no_author = mapper::to_author("a,alice,alice@wonderland.not,whatever");
+
+	assert_eq!(no_author, None);
+}
+
+#[test]
+fn map_to_csv_line() {
+	let alias = "a";
+	let name = "alice";
+	let email = "alice@wonderland.not";
+
+	let csv_line = mapper::to_line(&Author::from(alias, name, email));
+
+	assert_eq!(csv_line, format!("{alias},{name},{email}"));
+}
+
+#[test]
+fn map_inactive_author_to_csv_line() {
+	let csv_line = mapper::to_line(&Author::from("a", "alice", "alice@wonderland.not").deactivated());
+
+	assert_eq!(csv_line, "a,alice,alice@wonderland.not,inactive");
+}
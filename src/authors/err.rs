@@ -4,6 +4,9 @@ use std::{any::Any, fmt::Display};
 #[derive(Debug)]
 pub enum AuthorsError {
 	NotFound(String),
+	UnknownAlias(String),
+	UnknownRole(String),
+	InvalidTemplate(String),
 }
 
 impl Error for AuthorsError {
@@ -16,7 +19,13 @@ impl std::error::Error for AuthorsError {}
 
 impl PartialEq for AuthorsError {
 	fn eq(&self, other: &Self) -> bool {
-		matches!((self, other), (AuthorsError::NotFound(_), AuthorsError::NotFound(_)))
+		matches!(
+			(self, other),
+			(AuthorsError::NotFound(_), AuthorsError::NotFound(_))
+				| (AuthorsError::UnknownAlias(_), AuthorsError::UnknownAlias(_))
+				| (AuthorsError::UnknownRole(_), AuthorsError::UnknownRole(_))
+				| (AuthorsError::InvalidTemplate(_), AuthorsError::InvalidTemplate(_))
+		)
 	}
 }
 
@@ -25,6 +34,9 @@ impl Display for AuthorsError {
 		write!(f, "Authors failure: ")?;
 		match self {
 			AuthorsError::NotFound(location) => write!(f, "No authors file at {location}"),
+			AuthorsError::UnknownAlias(alias) => write!(f, "No author found for alias {alias}"),
+			AuthorsError::UnknownRole(role) => write!(f, "No trailer configured for role {role}"),
+			AuthorsError::InvalidTemplate(template) => write!(f, "Invalid signature template {template}"),
 		}
 	}
 }
@@ -39,5 +51,17 @@ mod tests {
 			format!("{}", AuthorsError::NotFound("path/to/file".to_string())),
 			"Authors failure: No authors file at path/to/file"
 		);
+		assert_eq!(
+			format!("{}", AuthorsError::UnknownAlias("an_alias".to_string())),
+			"Authors failure: No author found for alias an_alias"
+		);
+		assert_eq!(
+			format!("{}", AuthorsError::UnknownRole("a_role".to_string())),
+			"Authors failure: No trailer configured for role a_role"
+		);
+		assert_eq!(
+			format!("{}", AuthorsError::InvalidTemplate("a_template".to_string())),
+			"Authors failure: Invalid signature template a_template"
+		);
 	}
 }
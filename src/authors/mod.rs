@@ -1,9 +1,11 @@
 pub mod author;
 pub mod di;
 pub mod err;
+pub mod role;
+pub mod template;
 
 pub mod csv {
-	mod mapper;
+	pub mod mapper;
 	pub mod provider;
 
 	#[cfg(test)]
@@ -16,3 +18,7 @@ pub mod csv {
 
 #[cfg(test)]
 mod author_should;
+#[cfg(test)]
+mod role_should;
+#[cfg(test)]
+mod template_should;
@@ -0,0 +1,36 @@
+use super::err::AuthorsError;
+use crate::common::conf;
+use crate::Result;
+
+/// An alias qualified with a trailer role, such as `r:bb` for `Reviewed-by`
+#[derive(Debug, PartialEq)]
+pub struct RoleAlias {
+	key: String,
+	alias: String,
+}
+
+impl RoleAlias {
+	pub fn from(key: &str, alias: &str) -> Self {
+		Self {
+			key: key.to_string(),
+			alias: alias.to_string(),
+		}
+	}
+
+	/// Unqualified aliases are plain co-authors and give `None`
+	pub fn parse(entry: &str) -> Result<Option<Self>> {
+		let Some((role, alias)) = entry.split_once(':') else {
+			return Ok(None);
+		};
+		let key = conf::trailer_role(role.trim()).ok_or_else(|| AuthorsError::UnknownRole(role.to_string()))?;
+		Ok(Some(Self::from(&key, alias.trim())))
+	}
+
+	pub fn key(&self) -> &str {
+		&self.key
+	}
+
+	pub fn alias(&self) -> &str {
+		&self.alias
+	}
+}
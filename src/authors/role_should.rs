@@ -0,0 +1,22 @@
+use crate::authors::err::AuthorsError;
+use crate::authors::role::RoleAlias;
+use crate::error::assert_error_type;
+
+#[test]
+fn leave_plain_aliases_alone() {
+	assert_eq!(RoleAlias::parse("bb").ok(), Some(None));
+}
+
+#[test]
+fn resolve_the_trailer_key_of_a_role() {
+	let role_alias = RoleAlias::parse("r:bb");
+
+	assert!(matches!(role_alias, Ok(Some(ref found)) if *found == RoleAlias::from("Reviewed-by", "bb")));
+}
+
+#[test]
+fn error_on_unknown_roles() {
+	let result = RoleAlias::parse("nope:bb");
+
+	assert_error_type(&result, &AuthorsError::UnknownRole("nope".to_string()));
+}
@@ -0,0 +1,85 @@
+use super::author::Author;
+use super::err::AuthorsError;
+use crate::common::conf;
+use crate::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+	Alias,
+	Name,
+	Email,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Segment {
+	Text(String),
+	Field(Field),
+}
+
+/// What follows the trailer key in a signature, e.g. `{name} (@{alias}) <{email}>`
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureTemplate {
+	segments: Vec<Segment>,
+}
+
+impl Default for SignatureTemplate {
+	fn default() -> Self {
+		Self {
+			segments: vec![
+				Segment::Field(Field::Name),
+				Segment::Text(" <".to_string()),
+				Segment::Field(Field::Email),
+				Segment::Text(">".to_string()),
+			],
+		}
+	}
+}
+
+impl SignatureTemplate {
+	pub fn configured() -> Result<Self> {
+		Self::parse(&conf::signature_template())
+	}
+
+	/// The email is mandatory, as it is what ties a signature to an account (and to the same author on amend)
+	pub fn parse(template: &str) -> Result<Self> {
+		let invalid = |reason: &str| AuthorsError::InvalidTemplate(format!("{template} ({reason})"));
+		let mut segments = Vec::new();
+		let mut rest = template;
+		while let Some(open) = rest.find('{') {
+			if open > 0 {
+				segments.push(Segment::Text(rest[..open].to_string()));
+			}
+			let close = rest[open..].find('}').ok_or_else(|| invalid("unclosed placeholder"))? + open;
+			let field = match &rest[open + 1..close] {
+				"alias" => Field::Alias,
+				"name" => Field::Name,
+				"email" => Field::Email,
+				other => return Err(invalid(&format!("unknown placeholder {{{other}}}")).into()),
+			};
+			segments.push(Segment::Field(field));
+			rest = &rest[close + 1..];
+		}
+		if rest.contains('}') {
+			return Err(invalid("unopened placeholder").into());
+		}
+		if !rest.is_empty() {
+			segments.push(Segment::Text(rest.to_string()));
+		}
+		if !segments.contains(&Segment::Field(Field::Email)) {
+			return Err(invalid("missing {email}").into());
+		}
+		Ok(Self { segments })
+	}
+
+	pub fn render(&self, author: &Author) -> String {
+		self.segments
+			.iter()
+			.map(|segment| match segment {
+				Segment::Text(text) => text.clone(),
+				Segment::Field(Field::Alias) => author.alias(),
+				Segment::Field(Field::Name) => author.name(),
+				Segment::Field(Field::Email) => author.email(),
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,34 @@
+use crate::authors::author::Author;
+use crate::authors::err::AuthorsError;
+use crate::authors::template::SignatureTemplate;
+use crate::error::assert_error_type;
+use parameterized::parameterized;
+
+#[test]
+fn render_every_author_field() -> crate::Result<()> {
+	let template = SignatureTemplate::parse("{name} (@{alias}) <{email}>")?;
+
+	let signature = template.render(&Author::from("alice", "Alice", "alice@wonderland.not"));
+
+	assert_eq!(signature, "Alice (@alice) <alice@wonderland.not>");
+	Ok(())
+}
+
+#[test]
+fn default_to_name_and_email() {
+	let signature = SignatureTemplate::default().render(&Author::from("a", "Alice", "alice@wonderland.not"));
+
+	assert_eq!(signature, "Alice <alice@wonderland.not>");
+}
+
+#[parameterized(template = {
+	"{name} <{mail}>",
+	"{name} <{email",
+	"{name} <email}>",
+	"{name}",
+})]
+fn reject_invalid_templates(template: &str) {
+	let result = SignatureTemplate::parse(template);
+
+	assert_error_type(&result, &AuthorsError::InvalidTemplate(String::new()));
+}
@@ -11,6 +11,42 @@ pub fn authors_file() -> String {
 	get_config().get::<String>("authors_file").expect(CONFIG_ERR_MSG)
 }
 
+pub fn rota_file() -> String {
+	get_config().get::<String>("rota_file").expect(CONFIG_ERR_MSG)
+}
+
+pub fn templates_dir() -> String {
+	get_config().get::<String>("templates_dir").expect(CONFIG_ERR_MSG)
+}
+
+pub fn ticket_pattern() -> String {
+	get_config().get::<String>("ticket_pattern").expect(CONFIG_ERR_MSG)
+}
+
+pub fn ticket_from_branch() -> String {
+	get_config().get::<String>("ticket_from_branch").expect(CONFIG_ERR_MSG)
+}
+
+pub fn ticket_prefix() -> String {
+	get_config().get::<String>("ticket_prefix").expect(CONFIG_ERR_MSG)
+}
+
+pub fn ticket_trailer() -> String {
+	get_config().get::<String>("ticket_trailer").expect(CONFIG_ERR_MSG)
+}
+
+pub fn history() -> String {
+	get_config().get::<String>("history").expect(CONFIG_ERR_MSG)
+}
+
+pub fn history_size() -> usize {
+	get_config().get::<usize>("history_size").expect(CONFIG_ERR_MSG)
+}
+
+pub fn preview() -> bool {
+	get_config().get::<bool>("preview").expect(CONFIG_ERR_MSG)
+}
+
 pub fn hooks_path() -> String {
 	get_config().get::<String>("hooks_path").expect(CONFIG_ERR_MSG)
 }
@@ -23,6 +59,119 @@ pub fn co_author_prefix() -> String {
 	get_config().get::<String>("co_author_prefix").expect(CONFIG_ERR_MSG)
 }
 
+/// The canonical prefix first, then the configured equivalents
+pub fn co_author_prefixes() -> Vec<String> {
+	let aliases = get_config().get::<String>("co_author_aliases").expect(CONFIG_ERR_MSG);
+	std::iter::once(co_author_prefix())
+		.chain(
+			aliases
+				.split(',')
+				.map(str::trim)
+				.filter(|alias| !alias.is_empty())
+				.map(String::from),
+		)
+		.collect()
+}
+
+pub fn signature_template() -> String {
+	get_config().get::<String>("signature_template").expect(CONFIG_ERR_MSG)
+}
+
+pub fn trailer_role(role: &str) -> Option<String> {
+	let roles = get_config().get::<String>("trailer_roles").expect(CONFIG_ERR_MSG);
+	roles
+		.split(',')
+		.filter_map(|pair| pair.split_once('='))
+		.find(|(name, _)| name.trim().eq_ignore_ascii_case(role))
+		.map(|(_, key)| key.trim().to_string())
+}
+
+pub fn legacy_trailer_spacing() -> bool {
+	get_config()
+		.get::<bool>("legacy_trailer_spacing")
+		.expect(CONFIG_ERR_MSG)
+}
+
+pub fn conventional_commits() -> bool {
+	get_config().get::<bool>("conventional_commits").expect(CONFIG_ERR_MSG)
+}
+
+pub fn conventional_types() -> Vec<String> {
+	let types = get_config().get::<String>("conventional_types").expect(CONFIG_ERR_MSG);
+	types
+		.split(',')
+		.map(str::trim)
+		.filter(|kind| !kind.is_empty())
+		.map(String::from)
+		.collect()
+}
+
+pub fn lint_subject_max_length() -> usize {
+	get_config()
+		.get::<usize>("lint_subject_max_length")
+		.expect(CONFIG_ERR_MSG)
+}
+
+pub fn lint_subject_no_period() -> bool {
+	get_config()
+		.get::<bool>("lint_subject_no_period")
+		.expect(CONFIG_ERR_MSG)
+}
+
+pub fn lint_imperative_mood() -> bool {
+	get_config().get::<bool>("lint_imperative_mood").expect(CONFIG_ERR_MSG)
+}
+
+pub fn lint_body_max_width() -> usize {
+	get_config().get::<usize>("lint_body_max_width").expect(CONFIG_ERR_MSG)
+}
+
+pub fn lint_require_ticket() -> bool {
+	get_config().get::<bool>("lint_require_ticket").expect(CONFIG_ERR_MSG)
+}
+
+pub fn lint_ticket_matches_branch() -> bool {
+	get_config()
+		.get::<bool>("lint_ticket_matches_branch")
+		.expect(CONFIG_ERR_MSG)
+}
+
+pub fn lint_min_co_authors() -> usize {
+	get_config().get::<usize>("lint_min_co_authors").expect(CONFIG_ERR_MSG)
+}
+
+pub fn lint_min_co_authors_branches() -> Vec<String> {
+	let branches = get_config()
+		.get::<String>("lint_min_co_authors_branches")
+		.expect(CONFIG_ERR_MSG);
+	branches
+		.split(',')
+		.map(str::trim)
+		.filter(|branch| !branch.is_empty())
+		.map(String::from)
+		.collect()
+}
+
+pub fn session_ref() -> String {
+	get_config().get::<String>("session_ref").expect(CONFIG_ERR_MSG)
+}
+
+pub fn mob_remote() -> String {
+	get_config().get::<String>("mob_remote").expect(CONFIG_ERR_MSG)
+}
+
+pub fn wip_branch_prefix() -> String {
+	get_config().get::<String>("wip_branch_prefix").expect(CONFIG_ERR_MSG)
+}
+
+pub fn wip_commit_message() -> String {
+	get_config().get::<String>("wip_commit_message").expect(CONFIG_ERR_MSG)
+}
+
+pub fn timer_notify_cmd() -> String {
+	get_config().get::<String>("timer_notify_cmd").expect(CONFIG_ERR_MSG)
+}
+
 fn get_config() -> Config {
 	Config::builder()
 		.add_source(File::from_str(CONFIG, FileFormat::Yaml))
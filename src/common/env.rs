@@ -16,6 +16,11 @@ pub fn xdg_config() -> Result<String> {
 	Ok(std::env::var(xdg_home).map_err(|_| SystemError::EnvVar(xdg_home.to_string()))?)
 }
 
+pub fn xdg_state() -> Result<String> {
+	let xdg_state = "XDG_STATE_HOME";
+	Ok(std::env::var(xdg_state).map_err(|_| SystemError::EnvVar(xdg_state.to_string()))?)
+}
+
 pub fn editor() -> Result<String> {
 	let editor = "EDITOR";
 	Ok(std::env::var(editor).map_err(|_| SystemError::EnvVar(editor.to_string()))?)
@@ -1,13 +1,16 @@
 use crate::{common::err::SystemError, Result};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 type Lines = Vec<String>;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait Reader {
 	fn read_non_empty_lines(&self, path: &Path) -> Result<Lines>;
+	fn read(&self, path: &Path) -> Result<String>;
+	/// The files right in the directory, sub-directories left out
+	fn list_files(&self, dir: &Path) -> Result<Vec<PathBuf>>;
 }
 
 pub struct FileReader;
@@ -22,4 +25,17 @@ impl Reader for FileReader {
 				.collect(),
 		)
 	}
+
+	fn read(&self, path: &Path) -> Result<String> {
+		Ok(std::fs::read_to_string(path).map_err(|e| SystemError::Read(e.to_string()))?)
+	}
+
+	fn list_files(&self, dir: &Path) -> Result<Vec<PathBuf>> {
+		Ok(std::fs::read_dir(dir)
+			.map_err(|e| SystemError::Read(e.to_string()))?
+			.flatten()
+			.map(|entry| entry.path())
+			.filter(|path| path.is_file())
+			.collect())
+	}
 }
@@ -28,3 +28,48 @@ fn error_when_reading_lines_from_non_existent_file() {
 
 	assert!(non_empyt_lines.is_err());
 }
+
+#[test]
+fn read_the_whole_file() {
+	let (mut file, path) = random_tmp_file::create();
+	file.write_all(b"one\n\ntwo\n")
+		.expect("Could not write to file for test");
+
+	let reader = FileReader;
+	let content = reader.read(&PathBuf::from(path.clone()));
+
+	fs::remove_file(path).expect("Could not cleanup file for test");
+	assert!(matches!(content, Ok(content) if content == "one\n\ntwo\n"));
+}
+
+#[test]
+fn error_when_reading_non_existent_file() {
+	let path_to_no_file = random_tmp_file::path();
+
+	let reader = FileReader;
+	let content = reader.read(&PathBuf::from(path_to_no_file.as_str()));
+
+	assert!(content.is_err());
+}
+
+#[test]
+fn list_the_files_of_a_dir() {
+	let (_, path) = random_tmp_file::create();
+	let dir = PathBuf::from(&path).parent().map(PathBuf::from).unwrap_or_default();
+
+	let reader = FileReader;
+	let files = reader.list_files(&dir);
+
+	fs::remove_file(&path).expect("Could not cleanup file for test");
+	assert!(matches!(files, Ok(files) if files.contains(&PathBuf::from(path))));
+}
+
+#[test]
+fn error_when_listing_a_non_existent_dir() {
+	let path_to_no_dir = random_tmp_file::path();
+
+	let reader = FileReader;
+	let files = reader.list_files(&PathBuf::from(path_to_no_dir.as_str()));
+
+	assert!(files.is_err());
+}
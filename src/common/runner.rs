@@ -5,6 +5,8 @@ use std::process::{Child, Command, Stdio};
 #[cfg_attr(test, mockall::automock)]
 pub trait Runner {
 	fn run(&self, cmd: &str, arg: &str) -> Result<()>;
+	/// A command line through `sh`, the args being `$1`, `$2`...
+	fn run_script(&self, script: &str, args: &[String]) -> Result<()>;
 	fn spawn(&self, editor: &str, arg: &str) -> Result<()>;
 	fn attach(&self, cmd: &str, args: &[String]) -> Result<Child>;
 }
@@ -16,7 +18,7 @@ impl Runner for CommandRunner {
 		Ok(Command::new(cmd)
 			.arg(arg)
 			.spawn()
-			.map(|mut child| (child.wait()))
+			.map(|mut child| child.wait())
 			.map(|_| ())
 			.map_err(|e| SystemError::Runner(cmd.to_string(), e.to_string()))?)
 	}
@@ -34,6 +36,21 @@ impl Runner for CommandRunner {
 		}
 	}
 
+	fn run_script(&self, script: &str, args: &[String]) -> Result<()> {
+		let status = Command::new("sh")
+			.arg("-c")
+			.arg(script)
+			.arg("sh")
+			.args(args)
+			.status()
+			.map_err(|e| SystemError::Runner(script.to_string(), e.to_string()))?;
+		match status.code() {
+			_ if status.success() => Ok(()),
+			Some(code) => Err(SystemError::Runner(script.to_string(), format!("exit code {code}")).into()),
+			None => Err(SystemError::Runner(script.to_string(), "killed by a signal".to_string()).into()),
+		}
+	}
+
 	fn attach(&self, cmd: &str, args: &[String]) -> Result<Child> {
 		Ok(Command::new(cmd)
 			.args(args)
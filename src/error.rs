@@ -1,4 +1,7 @@
-use crate::{authors::err::AuthorsError, common::err::SystemError, git::err::GitError, ui::err::UiError};
+use crate::{
+	authors::err::AuthorsError, common::err::SystemError, git::err::GitError, mob::err::MobError,
+	templates::err::TemplatesError, ui::err::UiError,
+};
 use std::any::Any;
 
 pub trait Error: std::error::Error + Any {
@@ -23,6 +26,18 @@ impl From<GitError> for Box<dyn Error> {
 	}
 }
 
+impl From<MobError> for Box<dyn Error> {
+	fn from(e: MobError) -> Box<dyn Error> {
+		Box::new(e)
+	}
+}
+
+impl From<TemplatesError> for Box<dyn Error> {
+	fn from(e: TemplatesError) -> Box<dyn Error> {
+		Box::new(e)
+	}
+}
+
 impl From<SystemError> for Box<dyn Error> {
 	fn from(e: SystemError) -> Box<dyn Error> {
 		Box::new(e)
@@ -39,7 +54,7 @@ impl From<git2::Error> for Box<dyn Error> {
 pub fn assert_error_type<T, E: Error + 'static + PartialEq>(result: &crate::Result<T>, expected_error: &E) {
 	assert!(result.is_err(), "Not an Error");
 	assert!(
-		matches!(result, Err(ref e) if e.as_any().downcast_ref::<E>().map_or(false, |err| *err == *expected_error)),
+		matches!(result, Err(ref e) if e.as_any().downcast_ref::<E>().is_some_and(|err| *err == *expected_error)),
 		"Expected error type: {:?}, but got: {:?}",
 		expected_error,
 		result.as_ref().err(),
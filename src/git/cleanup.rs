@@ -0,0 +1,60 @@
+use git2::Config;
+
+/// The line below which everything is ignored in scissors mode
+pub fn scissors(comment: &str) -> String {
+	format!("{comment} ------------------------ >8 ------------------------")
+}
+
+/// The message above the scissors line, all of it without one
+pub fn cut_at_scissors<'a>(message: &'a str, comment: &str) -> &'a str {
+	let scissors = scissors(comment);
+	let end = message
+		.split_inclusive('\n')
+		.take_while(|line| line.trim_end() != scissors)
+		.map(str::len)
+		.sum();
+	&message[..end]
+}
+
+/// How the commit message is cleaned up, see `--cleanup` in git-commit
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Cleanup {
+	Strip,
+	Whitespace,
+	Verbatim,
+	Scissors,
+	#[default]
+	Default,
+}
+
+impl Cleanup {
+	pub fn parse(mode: &str) -> Option<Self> {
+		match mode.to_lowercase().as_str() {
+			"strip" => Some(Self::Strip),
+			"whitespace" => Some(Self::Whitespace),
+			"verbatim" => Some(Self::Verbatim),
+			"scissors" => Some(Self::Scissors),
+			"default" => Some(Self::Default),
+			_ => None,
+		}
+	}
+
+	/// `commit.cleanup`, unset or unknown values being git's default
+	pub fn from(config: &Config) -> Self {
+		config
+			.get_string("commit.cleanup")
+			.ok()
+			.and_then(|mode| Self::parse(&mode))
+			.unwrap_or_default()
+	}
+
+	/// Comments are only stripped by default when the message went through the editor, and so does scissors
+	pub fn resolve(self, edited: bool) -> Self {
+		match self {
+			Self::Default if edited => Self::Strip,
+			Self::Default => Self::Whitespace,
+			Self::Scissors if !edited => Self::Whitespace,
+			other => other,
+		}
+	}
+}
@@ -0,0 +1,37 @@
+/// Candidates tried in order by `core.commentChar=auto`, as git does
+const AUTO_CANDIDATES: &str = "#;@!$%^&|:";
+
+/// Prefix of the lines ignored in `COMMIT_EDITMSG`, see `core.commentString` in git-config
+#[derive(Debug, Clone, PartialEq)]
+pub enum CommentString {
+	Fixed(String),
+	Auto,
+}
+
+impl Default for CommentString {
+	fn default() -> Self {
+		Self::Fixed("#".to_string())
+	}
+}
+
+impl CommentString {
+	pub fn from(value: Option<String>) -> Self {
+		match value {
+			Some(value) if value.eq_ignore_ascii_case("auto") => Self::Auto,
+			Some(value) if !value.is_empty() => Self::Fixed(value),
+			_ => Self::default(),
+		}
+	}
+
+	/// In auto mode, the first candidate no line of the message starts with
+	pub fn resolve(&self, message: &str) -> String {
+		match self {
+			Self::Fixed(comment) => comment.clone(),
+			Self::Auto => AUTO_CANDIDATES
+				.chars()
+				.find(|candidate| !message.lines().any(|line| line.trim_start().starts_with(*candidate)))
+				.unwrap_or('#')
+				.to_string(),
+		}
+	}
+}
@@ -1,12 +1,20 @@
+use super::cleanup::Cleanup;
+use super::identity::Identity;
+
 pub enum CommitMode<'a> {
 	WithEditor {
 		message: Option<&'a str>,
 		authors: Vec<String>,
 		amend: bool,
+		driver: Option<Identity>,
+		cleanup: Option<Cleanup>,
+		verbose: bool,
 	},
 	WithoutEditor {
 		message: &'a str,
 		authors: Vec<String>,
 		amend: bool,
+		driver: Option<Identity>,
+		cleanup: Option<Cleanup>,
 	},
 }
@@ -0,0 +1,141 @@
+use super::err::GitError;
+use super::ticket::TicketPattern;
+use super::CommitMessage;
+use crate::common::conf;
+use crate::Result;
+use std::fmt::Display;
+use std::path::Path;
+
+/// The subject of a Conventional Commit: `type(scope)!: description`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Subject {
+	kind: String,
+	scope: Option<String>,
+	breaking: bool,
+	description: String,
+}
+
+impl Subject {
+	pub fn new(kind: &str, scope: Option<&str>, breaking: bool, description: &str) -> Self {
+		Self {
+			kind: kind.trim().to_string(),
+			scope: scope.map(str::trim).filter(|scope| !scope.is_empty()).map(String::from),
+			breaking,
+			description: description.trim().to_string(),
+		}
+	}
+
+	pub fn parse(subject: &str) -> Option<Self> {
+		let (head, description) = subject.split_once(": ")?;
+		let (head, breaking) = head.strip_suffix('!').map_or((head, false), |head| (head, true));
+		let (kind, scope) = match head.split_once('(') {
+			Some((kind, scope)) => (kind, Some(scope.strip_suffix(')')?)),
+			None => (head, None),
+		};
+		let is_word = |word: &str| !word.is_empty() && word.chars().all(|c| c.is_alphanumeric() || c == '-');
+		if !is_word(kind) || scope.is_some_and(|scope| scope.trim().is_empty() || scope.contains(['(', ')'])) {
+			return None;
+		}
+		if description.trim().is_empty() {
+			return None;
+		}
+		Some(Self::new(kind, scope, breaking, description))
+	}
+
+	pub fn kind(&self) -> &str {
+		&self.kind
+	}
+
+	pub fn description(&self) -> &str {
+		&self.description
+	}
+
+	/// The whole message, a breaking change being described in a `BREAKING CHANGE:` footer when given
+	pub fn message(&self, breaking_change: Option<&str>) -> String {
+		match breaking_change.map(str::trim).filter(|change| !change.is_empty()) {
+			Some(change) if self.breaking => format!("{self}\n\nBREAKING CHANGE: {change}"),
+			_ => self.to_string(),
+		}
+	}
+}
+
+impl Display for Subject {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.kind)?;
+		if let Some(scope) = &self.scope {
+			write!(f, "({scope})")?;
+		}
+		if self.breaking {
+			write!(f, "!")?;
+		}
+		write!(f, ": {}", self.description)
+	}
+}
+
+/// The commit types allowed by Conventional Commits
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conventional {
+	types: Vec<String>,
+	tickets: TicketPattern,
+}
+
+impl Conventional {
+	pub fn new(types: Vec<String>) -> Self {
+		Self {
+			types,
+			tickets: TicketPattern::default(),
+		}
+	}
+
+	/// A leading reference to one of them, as prefixed from the branch, comes before the type
+	pub fn with_tickets(mut self, tickets: TicketPattern) -> Self {
+		self.tickets = tickets;
+		self
+	}
+
+	pub fn configured() -> Self {
+		Self::new(conf::conventional_types())
+	}
+
+	pub fn types(&self) -> &[String] {
+		&self.types
+	}
+
+	pub fn is_known(&self, kind: &str) -> bool {
+		self.types.iter().any(|known| known == kind)
+	}
+
+	pub fn validate(&self, message: &CommitMessage) -> Result<()> {
+		let subject = Subject::parse(self.tickets.strip_leading(message.subject())).ok_or_else(|| {
+			GitError::Conventional(format!(
+				"\"{}\" is not a conventional commit subject",
+				message.subject()
+			))
+		})?;
+		if !self.is_known(subject.kind()) {
+			return Err(GitError::Conventional(format!(
+				"Unknown commit type {}, expected one of {}",
+				subject.kind(),
+				self.types.join(", ")
+			))
+			.into());
+		}
+		Ok(())
+	}
+}
+
+/// The name of the deepest directory holding all the paths, if any
+pub fn scope_of(paths: &[String]) -> Option<String> {
+	let mut dirs = paths
+		.iter()
+		.map(|path| Path::new(path).parent().unwrap_or(Path::new("")));
+	let mut common = dirs.next()?.to_path_buf();
+	for dir in dirs {
+		while !dir.starts_with(&common) {
+			if !common.pop() {
+				return None;
+			}
+		}
+	}
+	common.file_name().map(|name| name.to_string_lossy().to_string())
+}
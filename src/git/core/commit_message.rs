@@ -1,63 +1,212 @@
-use crate::{common::conf, Result};
+use super::trailer_rules::TrailerRules;
+use crate::git::cleanup::{cut_at_scissors, Cleanup};
+use crate::{common::conf, git::identity::Identity, Result};
 use std::fmt::Display;
 
-#[derive(Debug, Default, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct CommitMessage {
 	subject: String,
 	body: Vec<String>,
-	authors: Vec<String>,
+	trailers: Vec<String>,
+	verbatim: Option<String>,
 }
 
 impl CommitMessage {
-	pub fn new(message: &str, authors: Vec<String>) -> Self {
-		let mut lines = message.lines().map(str::trim);
-		let subject = lines.next().unwrap_or_default().to_string();
-		let body = lines
-			.filter(|line| !line.is_empty())
-			.map(String::from)
-			.collect::<Vec<String>>();
-
-		Self { subject, body, authors }
+	pub fn new(message: &str, authors: Vec<String>, rules: &TrailerRules) -> Self {
+		let mut commit_message = Self::parse(message.lines(), rules);
+		// Signatures written with another template are updated rather than duplicated
+		for author in authors.iter().filter(|line| is_co_author(line)) {
+			for trailer in &mut commit_message.trailers {
+				if is_co_author(trailer) && email_of(trailer).is_some() && email_of(trailer) == email_of(author) {
+					trailer.clone_from(author);
+				}
+			}
+		}
+		commit_message.trailers = rules.merge(&commit_message.trailers, authors);
+		commit_message
 	}
 
 	pub fn from(message: &str) -> Self {
-		let mut non_empty_lines = message
-			.lines()
-			.map(str::trim)
-			.filter(|line| !line.is_empty())
-			.filter(|line| !line.starts_with('#'));
+		Self::from_with(message, &TrailerRules::default(), Cleanup::Strip, "#")
+	}
+
+	/// The cleanup is expected to be resolved already, `Cleanup::Default` is taken as `Cleanup::Strip`.
+	/// Everything from the scissors line on is dropped when cutting there or stripping, as the editor puts the diff there
+	pub fn from_with(message: &str, rules: &TrailerRules, cleanup: Cleanup, comment: &str) -> Self {
+		let message = match cleanup {
+			Cleanup::Scissors | Cleanup::Strip | Cleanup::Default => cut_at_scissors(message, comment),
+			Cleanup::Whitespace | Cleanup::Verbatim => message,
+		};
+		let lines = message.lines();
+		match cleanup {
+			Cleanup::Strip | Cleanup::Default => Self::parse(lines.filter(|line| !line.starts_with(comment)), rules),
+			Cleanup::Whitespace | Cleanup::Scissors => Self::parse(lines, rules),
+			Cleanup::Verbatim => Self {
+				verbatim: Some(message.to_string()),
+				..Self::parse(lines, rules)
+			},
+		}
+	}
+
+	/// Trailers are taken from the trailing block (see git-interpret-trailers), co-author lines from anywhere
+	fn parse<'a>(lines: impl Iterator<Item = &'a str>, rules: &TrailerRules) -> Self {
+		let lines: Vec<&str> = lines.map(str::trim_end).collect();
+		let (subject, before, mut rest) = match lines
+			.iter()
+			.position(|line| !line.trim().is_empty() && !is_co_author(line.trim_start()))
+		{
+			Some(at) => (lines[at].trim().to_string(), &lines[..at], lines[at + 1..].to_vec()),
+			None => (String::new(), &lines[..], Vec::new()),
+		};
+
+		let block = match Self::trailer_block_start(&rest, rules) {
+			Some(start) => rest.split_off(start),
+			None => Vec::new(),
+		};
+		let (stray_authors, body): (Vec<&str>, Vec<&str>) =
+			rest.into_iter().partition(|line| is_co_author(line.trim_start()));
 
-		let subject = non_empty_lines.next().unwrap_or_default().to_string();
-		let (body, authors) = non_empty_lines
-			.map(String::from)
-			.partition(|line| !line.starts_with(&conf::co_author_prefix()));
+		let stray_authors = before
+			.iter()
+			.chain(stray_authors.iter())
+			.map(|line| line.trim_start())
+			.filter(|line| is_co_author(line));
+		let trailers = block.into_iter().chain(stray_authors).map(normalized).collect();
+		let body = Self::cleaned_up(&body);
 
-		Self { subject, body, authors }
+		Self {
+			subject,
+			body,
+			trailers,
+			verbatim: None,
+		}
+	}
+
+	/// Like git's default cleanup: surrounding and consecutive blank lines go, indentation stays
+	fn cleaned_up(lines: &[&str]) -> Vec<String> {
+		let mut cleaned: Vec<String> = Vec::new();
+		for line in lines {
+			let blank = line.trim().is_empty();
+			if blank && matches!(cleaned.last().map(String::as_str), None | Some("")) {
+				continue;
+			}
+			cleaned.push(if blank { String::new() } else { (*line).to_string() });
+		}
+		if cleaned.last().is_some_and(String::is_empty) {
+			cleaned.pop();
+		}
+		cleaned
+	}
+
+	/// The last paragraph is a trailer block if it only holds trailers and their continuation lines
+	fn trailer_block_start(lines: &[&str], rules: &TrailerRules) -> Option<usize> {
+		let end = lines.iter().rposition(|line| !line.is_empty())? + 1;
+		let start = lines[..end].iter().rposition(|line| line.is_empty())? + 1;
+		let paragraph = &lines[start..end];
+
+		let starts_with_trailer = rules.is_trailer(paragraph[0]);
+		let all_trailers = paragraph
+			.iter()
+			.all(|line| rules.is_trailer(line) || line.starts_with(char::is_whitespace));
+		(starts_with_trailer && all_trailers).then_some(start)
 	}
 
 	pub fn subject(&self) -> &str {
 		&self.subject
 	}
 
+	pub fn body(&self) -> &[String] {
+		&self.body
+	}
+
+	pub fn trailers(&self) -> &[String] {
+		&self.trailers
+	}
+
+	/// The same message without co-authors, for them to be selected again
+	pub fn without_co_authors(&self) -> Self {
+		Self {
+			trailers: self
+				.trailers
+				.iter()
+				.filter(|line| !is_co_author(line))
+				.cloned()
+				.collect(),
+			..self.clone()
+		}
+	}
+
+	pub fn authors(&self) -> Vec<String> {
+		self.trailers
+			.iter()
+			.filter(|line| is_co_author(line))
+			.cloned()
+			.collect()
+	}
+
 	pub fn formatted(&self) -> String {
+		if let Some(message) = &self.verbatim {
+			return message.clone();
+		}
 		let body = if self.body.is_empty() {
 			String::new()
 		} else {
 			format!("\n\n{}", self.body.join("\n"))
 		};
 
-		let authors = if self.authors.is_empty() {
+		// Git expects a single blank line before the trailer block
+		let separator = if conf::legacy_trailer_spacing() {
+			"\n\n\n"
+		} else {
+			"\n\n"
+		};
+		let trailers = if self.trailers.is_empty() {
 			String::new()
 		} else {
-			format!("\n\n\n{}", self.authors.join("\n"))
+			format!("{separator}{}", self.trailers.join("\n"))
 		};
 
-		format!("{}{}{}", self.subject, body, authors)
+		format!("{}{}{}", self.subject, body, trailers)
 	}
 
 	pub fn has_no_content(&self) -> bool {
 		self.subject.is_empty() && self.body.is_empty()
 	}
+
+	/// Same subject and body, whatever the trailers
+	pub fn has_same_content(&self, other: &Self) -> bool {
+		self.subject == other.subject && self.body == other.body
+	}
+}
+
+fn co_author_prefix_len(line: &str) -> Option<usize> {
+	let (key, _) = line.split_once(':')?;
+	let key = key.trim_end();
+	conf::co_author_prefixes()
+		.iter()
+		.any(|prefix| prefix.eq_ignore_ascii_case(key))
+		.then_some(key.len())
+}
+
+fn is_co_author(line: &str) -> bool {
+	co_author_prefix_len(line).is_some()
+}
+
+fn email_of(line: &str) -> Option<String> {
+	let start = line.rfind('<')? + 1;
+	let end = line[start..].find('>')? + start;
+	Some(line[start..end].to_lowercase())
+}
+
+/// Co-author lines are rewritten with the canonical prefix, whatever their spelling
+fn normalized(line: &str) -> String {
+	match co_author_prefix_len(line) {
+		Some(len) => {
+			let value = line[len..].trim_start().trim_start_matches(':');
+			format!("{}: {}", conf::co_author_prefix(), value.trim_start())
+		}
+		None => line.to_string(),
+	}
 }
 
 impl Display for CommitMessage {
@@ -68,8 +217,13 @@ impl Display for CommitMessage {
 
 #[cfg_attr(test, mockall::automock)]
 pub trait GitWrapper {
-	fn commit(&self) -> Result<()>;
-	fn amend(&self) -> Result<()>;
-	fn formatted_status(&self) -> Result<String>;
-	fn prev_commit_msg(&self) -> Result<CommitMessage>;
+	fn commit(&self, author: Option<Identity>, cleanup: Cleanup, comment: &str) -> Result<()>;
+	fn amend(&self, author: Option<Identity>, cleanup: Cleanup, comment: &str) -> Result<()>;
+	fn committer(&self) -> Result<Identity>;
+	fn formatted_status(&self, cleanup: Cleanup, comment: &str) -> Result<String>;
+	fn staged_diff(&self) -> Result<String>;
+	fn staged_paths(&self) -> Result<Vec<String>>;
+	fn staged_summary(&self) -> Result<Vec<String>>;
+	fn commit_msg(&self, rev: &str) -> Result<CommitMessage>;
+	fn pending_message(&self, cleanup: Cleanup, comment: &str) -> Result<CommitMessage>;
 }
@@ -1,8 +1,9 @@
-use git2::Config;
+use git2::{Config, Repository};
 
 #[cfg_attr(test, mockall::automock)]
 pub trait ConfProvider {
 	fn get_editor(&self) -> Option<String>;
+	fn get_comment_string(&self) -> Option<String>;
 }
 
 pub struct GitConfProvider;
@@ -11,4 +12,16 @@ impl ConfProvider for GitConfProvider {
 	fn get_editor(&self) -> Option<String> {
 		Config::open_default().ok()?.get_string("core.editor").ok()
 	}
+
+	/// `core.commentString` takes precedence over `core.commentChar`, repo settings over global ones
+	fn get_comment_string(&self) -> Option<String> {
+		let config = Repository::discover(".")
+			.and_then(|repo| repo.config())
+			.or_else(|_| Config::open_default())
+			.ok()?;
+		config
+			.get_string("core.commentString")
+			.or_else(|_| config.get_string("core.commentChar"))
+			.ok()
+	}
 }
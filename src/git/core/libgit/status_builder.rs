@@ -1,7 +1,11 @@
-use crate::{git::err::GitError, Result};
-use git2::{Repository, StatusEntry, StatusOptions, Statuses};
+use crate::{
+	git::{cleanup::Cleanup, err::GitError},
+	Result,
+};
+use git2::{Delta, DiffFile, DiffFormat, Repository, StatusEntry, StatusOptions, Statuses};
+use std::path::Path;
 
-pub fn for_editmsg(repo: &Repository) -> Result<String> {
+pub fn for_editmsg(repo: &Repository, cleanup: Cleanup, comment: &str) -> Result<String> {
 	let mut options = StatusOptions::new();
 	options.include_untracked(true);
 
@@ -11,24 +15,91 @@ pub fn for_editmsg(repo: &Repository) -> Result<String> {
 		.ok_or_else(|| GitError::LibGit("Could not get branch name".to_string()))?;
 	let file_statuses = repo.statuses(Some(&mut options))?;
 
+	// Worded like git, which only promises to drop the comments when it strips them
+	let hint = match cleanup {
+		Cleanup::Whitespace | Cleanup::Verbatim => format!(
+			"# Please enter the commit message for your changes. Lines starting
+# with '{comment}' will be kept; you may remove them yourself if you want to.
+# An empty message aborts the commit."
+		),
+		_ => format!(
+			"# Please enter the commit message for your changes. Lines starting
+# with '{comment}' will be ignored, and an empty message aborts the commit."
+		),
+	};
 	let heading = format!(
 		"
 
-# Please enter the commit message for your changes. Lines starting
-# with '#' will be ignored, and an empty message aborts the commit.
+{hint}
 #
 # A message with only 'Co-Authored' lines will be considered empty.
 #
 # On branch {branch_name}\n"
 	);
 
-	Ok(format!(
+	let status = format!(
 		"{}{}{}{}",
 		heading,
 		changes_to_be_committed(&file_statuses),
 		changes_not_staged_for_commit(&file_statuses),
 		untracked_files(&file_statuses)
-	))
+	);
+	Ok(with_comment(&status, comment))
+}
+
+/// What is about to be committed, as a patch
+pub fn staged_diff(repo: &Repository) -> Result<String> {
+	let head = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+	let diff = repo.diff_tree_to_index(head.as_ref(), Some(&repo.index()?), None)?;
+
+	let mut patch = String::new();
+	diff.print(DiffFormat::Patch, |_, _, line| {
+		if matches!(line.origin(), '+' | '-' | ' ') {
+			patch.push(line.origin());
+		}
+		patch.push_str(&String::from_utf8_lossy(line.content()));
+		true
+	})?;
+	Ok(patch)
+}
+
+/// Paths of the staged files, deleted ones included
+pub fn staged_paths(repo: &Repository) -> Result<Vec<String>> {
+	let head = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+	let diff = repo.diff_tree_to_index(head.as_ref(), Some(&repo.index()?), None)?;
+	Ok(diff
+		.deltas()
+		.filter_map(|delta| {
+			delta
+				.new_file()
+				.path()
+				.or_else(|| delta.old_file().path())
+				.map(Path::to_path_buf)
+		})
+		.map(|path| path.to_string_lossy().to_string())
+		.collect())
+}
+
+/// One line per staged file, worded like `git status`
+pub fn staged_summary(repo: &Repository) -> Result<Vec<String>> {
+	let head = repo.head().ok().and_then(|head| head.peel_to_tree().ok());
+	let mut diff = repo.diff_tree_to_index(head.as_ref(), Some(&repo.index()?), None)?;
+	diff.find_similar(None)?;
+	let path = |file: DiffFile| {
+		file.path()
+			.map(|path| path.to_string_lossy().to_string())
+			.unwrap_or_default()
+	};
+	Ok(diff
+		.deltas()
+		.map(|delta| match delta.status() {
+			Delta::Added => format!("new file:   {}", path(delta.new_file())),
+			Delta::Deleted => format!("deleted:    {}", path(delta.old_file())),
+			Delta::Renamed => format!("renamed:    {} -> {}", path(delta.old_file()), path(delta.new_file())),
+			Delta::Typechange => format!("typechange: {}", path(delta.new_file())),
+			_ => format!("modified:   {}", path(delta.new_file())),
+		})
+		.collect())
 }
 
 fn changes_to_be_committed(file_statuses: &Statuses) -> String {
@@ -86,3 +157,14 @@ fn untracked_files(file_statuses: &Statuses) -> String {
 fn format_path(file: StatusEntry) -> Option<String> {
 	file.path().map(|path| format!("#\t{path}\n"))
 }
+
+fn with_comment(status: &str, comment: &str) -> String {
+	status
+		.split('\n')
+		.map(|line| match line.strip_prefix('#') {
+			Some(rest) => format!("{comment}{rest}"),
+			None => line.to_string(),
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+}
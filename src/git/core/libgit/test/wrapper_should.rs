@@ -1,9 +1,13 @@
 use crate::common::fs::file_reader::FileReader;
+use crate::error::{assert_error_contains_msg, assert_error_type};
+use crate::git::cleanup::Cleanup;
 use crate::git::core::commit_message::GitWrapper;
 use crate::git::core::libgit::test::helper::{
 	create_and_add_file_to_git_tree, init_repo, random_tmp_path_in, TEST_DIR_PATH,
 };
 use crate::git::core::libgit::wrapper::LibGitWrapper;
+use crate::git::err::GitError;
+use crate::git::identity::Identity;
 use crate::Result;
 use git2::Repository;
 use std::fs::{self};
@@ -29,7 +33,7 @@ fn add_status_to_editmsg_file() -> Result<()> {
 	add_commit(&git_repo, &tree, "IRRELEVANT")?;
 
 	let repo = LibGitWrapper::from(&path, FileReader)?;
-	let contents = repo.formatted_status()?;
+	let contents = repo.formatted_status(Cleanup::Strip, "#")?;
 
 	fs::remove_dir_all(path).ok();
 	assert_eq!(
@@ -55,6 +59,76 @@ fn add_status_to_editmsg_file() -> Result<()> {
 	Ok(())
 }
 
+#[test]
+fn comment_the_status_with_the_given_string() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let contents = repo.formatted_status(Cleanup::Strip, ";")?;
+
+	fs::remove_dir_all(path).ok();
+	assert!(contents.contains("; Please enter the commit message"));
+	assert!(contents.contains("; with ';' will be ignored"));
+	assert!(contents.lines().all(|line| !line.starts_with('#')));
+	Ok(())
+}
+
+#[test]
+fn warn_that_comments_are_kept_when_the_cleanup_does_not_strip_them() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let whitespace = repo.formatted_status(Cleanup::Whitespace, "#")?;
+	let verbatim = repo.formatted_status(Cleanup::Verbatim, "#")?;
+
+	fs::remove_dir_all(path).ok();
+	for contents in [whitespace, verbatim] {
+		assert!(contents.contains("# with '#' will be kept; you may remove them yourself if you want to."));
+		assert!(!contents.contains("will be ignored"));
+	}
+	Ok(())
+}
+
+#[test]
+fn render_the_staged_diff() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+	std::fs::write(path.join("foo"), "a line\n")?;
+	let mut index = git_repo.index()?;
+	index.add_path(std::path::Path::new("foo"))?;
+	index.write()?;
+	// unstaged changes are left out
+	std::fs::write(path.join("foo"), "a line\nanother line\n")?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let diff = repo.staged_diff()?;
+
+	fs::remove_dir_all(path).ok();
+	assert!(diff.starts_with("diff --git a/foo b/foo\n"));
+	assert!(diff.ends_with("@@ -0,0 +1 @@\n+a line\n"));
+	Ok(())
+}
+
+#[test]
+fn summarize_the_staged_files() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+	create_and_add_file_to_git_tree(&git_repo, "bar")?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let summary = repo.staged_summary()?;
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(summary, ["new file:   bar", "new file:   foo"]);
+	Ok(())
+}
+
 #[test]
 fn get_the_last_commit() -> Result<()> {
 	let path = random_tmp_path_in(TEST_DIR_PATH);
@@ -66,13 +140,48 @@ fn get_the_last_commit() -> Result<()> {
 	let msg = "a commit message!".to_string();
 	add_commit(&git_repo, &tree, msg.as_str())?;
 
-	let result = repo.prev_commit_msg();
+	let result = repo.commit_msg("HEAD");
 
 	fs::remove_dir_all(path.clone()).ok();
 	assert!(matches!(result, Ok(line) if line.to_string().contains(msg.as_str())));
 	Ok(())
 }
 
+#[test]
+fn get_the_message_of_any_commit() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+
+	let tree = git_repo.find_tree(git_repo.index()?.write_tree()?)?;
+	add_commit(&git_repo, &tree, "a subject\n\na body")?;
+	add_commit(&git_repo, &tree, "another commit")?;
+
+	let result = repo.commit_msg("HEAD~1");
+	let unknown = repo.commit_msg("no-such-rev");
+
+	fs::remove_dir_all(path.clone()).ok();
+	assert!(matches!(result, Ok(message) if message.to_string() == "a subject\n\na body"));
+	assert_error_type(&unknown, &GitError::LibGit(String::new()));
+	assert_error_contains_msg(&unknown, "Unknown revision no-such-rev");
+	Ok(())
+}
+
+#[test]
+fn get_the_committer_identity() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+
+	let result = repo.committer();
+
+	fs::remove_dir_all(path.clone()).ok();
+	assert!(matches!(result, Ok(identity) if identity == Identity::from("a_name", "an_email")));
+	Ok(())
+}
+
 fn add_commit(repo: &Repository, tree: &git2::Tree<'_>, msg: &str) -> Result<()> {
 	let head_commit = repo.head()?.peel_to_commit()?;
 	repo.commit(
@@ -1,11 +1,14 @@
 use crate::common::fs::file_reader::FileReader;
 use crate::error::{assert_error_contains_msg, assert_error_type};
+use crate::git::cleanup::Cleanup;
 use crate::git::core::commit_message::{CommitMessage, GitWrapper};
 use crate::git::core::libgit::test::helper::{
 	count_commits, create_and_add_file_to_git_tree, init_repo, random_tmp_path_in, TEST_DIR_PATH,
 };
 use crate::git::core::libgit::wrapper::LibGitWrapper;
+use crate::git::core::trailer_rules::TrailerRules;
 use crate::git::err::GitError;
+use crate::git::identity::Identity;
 use crate::Result;
 use std::fs::{self};
 
@@ -15,13 +18,17 @@ fn on_an_already_existing_git_repo_with_staged_changes() -> Result<()> {
 	let git_repo = init_repo(&path)?;
 	create_and_add_file_to_git_tree(&git_repo, "foo")?;
 
-	let commit_message = CommitMessage::new("irrelevant message", vec!["irrelevant author".to_string()]);
+	let commit_message = CommitMessage::new(
+		"irrelevant message",
+		vec!["irrelevant author".to_string()],
+		&TrailerRules::default(),
+	);
 	let editmsg_path = format!("{}/.git/COMMIT_EDITMSG", path.to_string_lossy());
 	std::fs::write(editmsg_path, commit_message.to_string())?;
 
 	let amount_of_commits_before = count_commits(&path)?;
 	let repo = LibGitWrapper::from(&path, FileReader)?;
-	let result = repo.commit();
+	let result = repo.commit(None, Cleanup::Strip, "#");
 	let amount_of_commits_after = count_commits(&path)?;
 
 	fs::remove_dir_all(path).ok();
@@ -36,12 +43,103 @@ fn not_if_message_is_empty() -> Result<()> {
 	let git_repo = init_repo(&path)?;
 	create_and_add_file_to_git_tree(&git_repo, "foo")?;
 
-	let empty_commit_message = CommitMessage::new("", vec![String::new()]);
+	let empty_commit_message = CommitMessage::new("", vec![String::new()], &TrailerRules::default());
 	let editmsg_path = format!("{}/.git/COMMIT_EDITMSG", path.to_string_lossy());
 	std::fs::write(editmsg_path, empty_commit_message.to_string())?;
 
 	let repo = LibGitWrapper::from(&path, FileReader)?;
-	let result = repo.commit();
+	let result = repo.commit(None, Cleanup::Strip, "#");
+
+	fs::remove_dir_all(path).ok();
+	assert_error_type(&result, &GitError::LibGit(String::new()));
+	assert_error_contains_msg(&result, "Commit message cannot be empty");
+	Ok(())
+}
+
+#[test]
+fn as_the_given_author_keeping_the_committer() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+
+	let commit_message = CommitMessage::new("irrelevant message", vec![], &TrailerRules::default());
+	let editmsg_path = format!("{}/.git/COMMIT_EDITMSG", path.to_string_lossy());
+	std::fs::write(editmsg_path, commit_message.to_string())?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let driver = Identity::from("a_driver", "a_driver@email");
+	let result = repo.commit(Some(driver.clone()), Cleanup::Strip, "#");
+	let head = git_repo.head()?.peel_to_commit()?;
+	let (author, committer) = (head.author().to_owned(), head.committer().to_owned());
+
+	fs::remove_dir_all(path).ok();
+	assert!(result.is_ok());
+	assert_eq!(author.name(), Some(driver.name()));
+	assert_eq!(author.email(), Some(driver.email()));
+	assert_eq!(committer.name(), Some("a_name"));
+	assert_eq!(committer.email(), Some("an_email"));
+	Ok(())
+}
+
+#[test]
+fn following_the_given_cleanup() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+
+	let editmsg_path = format!("{}/.git/COMMIT_EDITMSG", path.to_string_lossy());
+	std::fs::write(editmsg_path, "a subject\n\n#123 is fixed")?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let result = repo.commit(None, Cleanup::Whitespace, "#");
+	let message = git_repo.head()?.peel_to_commit()?.message().map(String::from);
+
+	fs::remove_dir_all(path).ok();
+	assert!(result.is_ok());
+	assert_eq!(message.as_deref(), Some("a subject\n\n#123 is fixed"));
+	Ok(())
+}
+
+#[test]
+fn not_if_the_template_is_left_untouched() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+	let template_path = path.join("template");
+	std::fs::write(&template_path, "a subject\n\n# what changed?\n")?;
+	git_repo
+		.config()?
+		.set_str("commit.template", &template_path.to_string_lossy())?;
+
+	let editmsg_path = format!("{}/.git/COMMIT_EDITMSG", path.to_string_lossy());
+	std::fs::write(editmsg_path, "a subject\n\nCo-authored-by: an author")?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let result = repo.commit(None, Cleanup::Strip, "#");
+
+	fs::remove_dir_all(path).ok();
+	assert_error_type(&result, &GitError::LibGit(String::new()));
+	assert_error_contains_msg(&result, "Commit message cannot be empty");
+	Ok(())
+}
+
+#[test]
+fn not_if_the_template_prompted_for_is_left_untouched() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let git_repo = init_repo(&path)?;
+	create_and_add_file_to_git_tree(&git_repo, "foo")?;
+	let template_path = path.join("template");
+	std::fs::write(&template_path, "a subject\n\n# what changed?\n")?;
+	git_repo
+		.config()?
+		.set_str("commit.template", &template_path.to_string_lossy())?;
+
+	let editmsg_path = format!("{}/.git/COMMIT_EDITMSG", path.to_string_lossy());
+	// The prompt starts from the template without its comments
+	std::fs::write(editmsg_path, "a subject\n\nCo-authored-by: an author")?;
+
+	let repo = LibGitWrapper::from(&path, FileReader)?;
+	let result = repo.commit(None, Cleanup::Whitespace, "#");
 
 	fs::remove_dir_all(path).ok();
 	assert_error_type(&result, &GitError::LibGit(String::new()));
@@ -1,8 +1,11 @@
 use super::status_builder;
 use crate::common::conf;
 use crate::common::fs::file_reader::Reader;
+use crate::git::cleanup::Cleanup;
 use crate::git::core::commit_message::{CommitMessage, GitWrapper};
+use crate::git::core::trailer_rules::TrailerRules;
 use crate::git::err::GitError;
+use crate::git::identity::Identity;
 use crate::Result;
 use git2::{Repository, Signature};
 use std::path::PathBuf;
@@ -11,19 +14,24 @@ pub struct LibGitWrapper<R: Reader> {
 	repo: Repository,
 	path: PathBuf,
 	reader: R,
+	trailer_rules: TrailerRules,
+	cleanup: Cleanup,
+	verbose: bool,
+	template: Option<String>,
 }
 
 impl<R: Reader> GitWrapper for LibGitWrapper<R> {
-	fn commit(&self) -> Result<()> {
-		let signature = self.validate_signature()?;
-		let commit_message = self.validate_commit_message()?;
+	fn commit(&self, author: Option<Identity>, cleanup: Cleanup, comment: &str) -> Result<()> {
+		let committer = self.validate_signature()?;
+		let author = Self::author_signature(author.as_ref(), &committer)?;
+		let commit_message = self.validate_commit_message(cleanup, comment)?;
 
 		match self.repo.head() {
 			// If there is a HEAD, take it as parent
 			Ok(head) => self.repo.commit(
 				head.name(),
-				&signature,
-				&signature,
+				&author,
+				&committer,
 				&commit_message.to_string(),
 				&self.get_tree()?,
 				&[&head.peel_to_commit()?],
@@ -33,8 +41,8 @@ impl<R: Reader> GitWrapper for LibGitWrapper<R> {
 			// First commit (maybe detached HEAD?)
 			Err(_) => self.repo.commit(
 				Some("HEAD"),
-				&signature,
-				&signature,
+				&author,
+				&committer,
 				&commit_message.to_string(),
 				&self.get_tree()?,
 				&[],
@@ -44,16 +52,17 @@ impl<R: Reader> GitWrapper for LibGitWrapper<R> {
 		.map_err(Into::into)
 	}
 
-	fn amend(&self) -> Result<()> {
-		let signature = self.validate_signature()?;
-		let commit_message = self.validate_commit_message()?;
+	fn amend(&self, author: Option<Identity>, cleanup: Cleanup, comment: &str) -> Result<()> {
+		let committer = self.validate_signature()?;
+		let author = Self::author_signature(author.as_ref(), &committer)?;
+		let commit_message = self.validate_commit_message(cleanup, comment)?;
 		let head = self.repo.head()?;
 
 		head.peel_to_commit()?
 			.amend(
 				head.name(),
-				Some(&signature),
-				Some(&signature),
+				Some(&author),
+				Some(&committer),
 				None,
 				Some(&commit_message.to_string()),
 				Some(&self.get_tree()?),
@@ -62,13 +71,52 @@ impl<R: Reader> GitWrapper for LibGitWrapper<R> {
 			.map_err(Into::into)
 	}
 
-	fn formatted_status(&self) -> Result<String> {
-		status_builder::for_editmsg(&self.repo)
+	fn committer(&self) -> Result<Identity> {
+		let signature = self.validate_signature()?;
+		Ok(Identity::from(
+			signature.name().unwrap_or_default(),
+			signature.email().unwrap_or_default(),
+		))
+	}
+
+	fn formatted_status(&self, cleanup: Cleanup, comment: &str) -> Result<String> {
+		status_builder::for_editmsg(&self.repo, cleanup, comment)
+	}
+
+	fn staged_diff(&self) -> Result<String> {
+		status_builder::staged_diff(&self.repo)
 	}
 
-	fn prev_commit_msg(&self) -> Result<CommitMessage> {
-		let last_commit = self.repo.head().and_then(|head_ref| head_ref.peel_to_commit())?;
-		Ok(CommitMessage::from(last_commit.message().unwrap_or_default()))
+	fn staged_paths(&self) -> Result<Vec<String>> {
+		status_builder::staged_paths(&self.repo)
+	}
+
+	fn staged_summary(&self) -> Result<Vec<String>> {
+		status_builder::staged_summary(&self.repo)
+	}
+
+	fn pending_message(&self, cleanup: Cleanup, comment: &str) -> Result<CommitMessage> {
+		Ok(CommitMessage::from_with(
+			&self.reader.read(&self.path.join(conf::editmsg()))?,
+			&self.trailer_rules,
+			cleanup,
+			comment,
+		))
+	}
+
+	fn commit_msg(&self, rev: &str) -> Result<CommitMessage> {
+		let commit = self
+			.repo
+			.revparse_single(rev)
+			.and_then(|object| object.peel_to_commit())
+			.map_err(|_| GitError::LibGit(format!("Unknown revision {rev}")))?;
+		// Already cleaned up when committed, so a leading `#123` is part of it
+		Ok(CommitMessage::from_with(
+			commit.message().unwrap_or_default(),
+			&self.trailer_rules,
+			Cleanup::Whitespace,
+			"#",
+		))
 	}
 }
 
@@ -78,14 +126,55 @@ impl<R: Reader> LibGitWrapper<R> {
 		if Self::no_staged_changes(&repo)? {
 			Err(Box::new(GitError::LibGit("No staged changes".to_string())))
 		} else {
+			let config = repo.config()?;
+			let trailer_rules = TrailerRules::from(&config);
+			let cleanup = Cleanup::from(&config);
+			// Like git, a verbosity level above 0 counts as verbose
+			let verbose = config
+				.get_bool("commit.verbose")
+				.or_else(|_| config.get_i32("commit.verbose").map(|level| level > 0))
+				.unwrap_or(false);
+			// An unreadable template is ignored rather than failing every commit
+			let template = config
+				.get_path("commit.template")
+				.ok()
+				.and_then(|template| file_reader.read(&template).ok());
 			Ok(Self {
 				repo,
 				path: path.clone(),
 				reader: file_reader,
+				trailer_rules,
+				cleanup,
+				verbose,
+				template,
 			})
 		}
 	}
 
+	pub fn branch(&self) -> String {
+		self.repo
+			.head()
+			.ok()
+			.and_then(|head| head.shorthand().map(String::from))
+			.unwrap_or_default()
+	}
+
+	pub fn trailer_rules(&self) -> TrailerRules {
+		self.trailer_rules.clone()
+	}
+
+	pub fn cleanup(&self) -> Cleanup {
+		self.cleanup
+	}
+
+	pub fn verbose(&self) -> bool {
+		self.verbose
+	}
+
+	pub fn template(&self) -> Option<String> {
+		self.template.clone()
+	}
+
 	fn no_staged_changes(repo: &Repository) -> Result<bool> {
 		let tree = repo.head()?.peel_to_tree()?;
 		let index = repo.index()?;
@@ -93,30 +182,38 @@ impl<R: Reader> LibGitWrapper<R> {
 		Ok(diff.deltas().count() == 0)
 	}
 
-	fn validate_signature(&self) -> Result<Signature> {
+	fn validate_signature(&self) -> Result<Signature<'_>> {
 		Ok(self
 			.repo
 			.signature()
 			.map_err(|_| GitError::LibGit("User name and/or email not set".to_string()))?)
 	}
 
-	fn validate_commit_message(&self) -> Result<CommitMessage> {
-		let commit_message = CommitMessage::from(
-			&self
-				.reader
-				.read_non_empty_lines(&self.path.join(conf::editmsg()))
-				.unwrap_or_default()
-				.join("\n"),
-		);
+	fn author_signature(author: Option<&Identity>, committer: &Signature) -> Result<Signature<'static>> {
+		match author {
+			Some(identity) => Ok(Signature::now(identity.name(), identity.email())?),
+			None => Ok(committer.to_owned()),
+		}
+	}
+
+	fn validate_commit_message(&self, cleanup: Cleanup, comment: &str) -> Result<CommitMessage> {
+		let commit_message = self.pending_message(cleanup, comment).unwrap_or_default();
 
-		if commit_message.has_no_content() {
+		// Like git, a template left as is doesn't make a message.
+		// Both are stripped alike, the prompt starting from the template without its comments
+		let stripped = |message: &str| CommitMessage::from_with(message, &self.trailer_rules, Cleanup::Strip, comment);
+		let untouched = self
+			.template
+			.as_ref()
+			.is_some_and(|template| stripped(template).has_same_content(&stripped(&commit_message.to_string())));
+		if commit_message.has_no_content() || untouched {
 			return Err(Box::new(GitError::LibGit("Commit message cannot be empty".to_string())));
 		}
 
 		Ok(commit_message)
 	}
 
-	fn get_tree(&self) -> Result<git2::Tree> {
+	fn get_tree(&self) -> Result<git2::Tree<'_>> {
 		Ok(self.repo.find_tree(self.repo.index()?.write_tree()?)?)
 	}
 }
@@ -1,7 +1,16 @@
+use super::super::cleanup::{cut_at_scissors, scissors, Cleanup};
+use super::super::comment::CommentString;
 use super::super::commit_mode::CommitMode;
+use super::super::conventional::{scope_of, Conventional};
+use super::super::err::GitError;
+use super::super::identity::Identity;
+use super::super::lint::LintRules;
+use super::super::preview::{Pending, Preview};
+use super::super::ticket::BranchTicket;
 use super::commit_message::{CommitMessage, GitWrapper};
 use super::editor::file_editor::Editor;
 use super::hook::HookRunner;
+use super::trailer_rules::TrailerRules;
 use crate::common::conf;
 use crate::common::fs::file_writer::Writer;
 use crate::Result;
@@ -13,6 +22,15 @@ pub struct GitService<G: GitWrapper, H: HookRunner, E: Editor, W: Writer> {
 	editmsg_editor: E,
 	file_writer: W,
 	editmsg_path: String,
+	trailer_rules: TrailerRules,
+	cleanup: Cleanup,
+	comment: CommentString,
+	verbose: bool,
+	template: Option<String>,
+	lint_rules: LintRules,
+	rejected: Option<CommitMessage>,
+	branch_ticket: Option<BranchTicket>,
+	conventional: Option<Conventional>,
 }
 
 impl<G: GitWrapper, H: HookRunner, E: Editor, W: Writer> GitService<G, H, E, W> {
@@ -24,36 +42,182 @@ impl<G: GitWrapper, H: HookRunner, E: Editor, W: Writer> GitService<G, H, E, W>
 			editmsg_editor,
 			file_writer,
 			editmsg_path,
+			trailer_rules: TrailerRules::default(),
+			cleanup: Cleanup::default(),
+			comment: CommentString::default(),
+			verbose: false,
+			template: None,
+			lint_rules: LintRules::default(),
+			rejected: None,
+			branch_ticket: None,
+			conventional: None,
 		}
 	}
 
+	pub fn with_trailer_rules(mut self, trailer_rules: TrailerRules) -> Self {
+		self.trailer_rules = trailer_rules;
+		self
+	}
+
+	pub fn with_cleanup(mut self, cleanup: Cleanup) -> Self {
+		self.cleanup = cleanup;
+		self
+	}
+
+	pub fn with_comment_string(mut self, comment: CommentString) -> Self {
+		self.comment = comment;
+		self
+	}
+
+	pub fn with_verbose(mut self, verbose: bool) -> Self {
+		self.verbose = verbose;
+		self
+	}
+
+	pub fn with_template(mut self, template: Option<String>) -> Self {
+		self.template = template;
+		self
+	}
+
+	pub fn with_lint_rules(mut self, lint_rules: LintRules) -> Self {
+		self.lint_rules = lint_rules;
+		self
+	}
+
+	pub fn with_branch_ticket(mut self, branch_ticket: Option<BranchTicket>) -> Self {
+		self.branch_ticket = branch_ticket;
+		self
+	}
+
+	/// Only Conventional Commits are let through
+	pub fn with_conventional(mut self, conventional: Option<Conventional>) -> Self {
+		self.conventional = conventional;
+		self
+	}
+
+	/// For commits not meant to last, such as the work in progress of a mob
+	pub fn without_conventional(self) -> Self {
+		self.with_conventional(None)
+	}
+
+	/// The message that last failed the checks, to be fixed up
+	pub fn rejected_message(&self) -> Option<&CommitMessage> {
+		self.rejected.as_ref()
+	}
+
+	/// Whether the co-authors, rather than the message, are to be fixed up
+	pub fn lacks_co_authors(&self) -> bool {
+		self.rejected
+			.as_ref()
+			.is_some_and(|message| self.lint_rules.check_co_authors(message).is_some())
+	}
+
+	/// The `commit.template` contents without comments, to start a prompted message from
+	pub fn template_message(&self) -> Option<String> {
+		let template = self.template.as_deref()?;
+		let comment = self.comment.resolve(template);
+		Some(CommitMessage::from_with(template, &self.trailer_rules, Cleanup::Strip, &comment).to_string())
+	}
+
+	/// The message of any commit, subject and body, co-authors left out as they get selected again
+	pub fn commit_message(&self, rev: &str) -> Result<String> {
+		Ok(self.git_wrapper.commit_msg(rev)?.without_co_authors().to_string())
+	}
+
 	pub fn last_commit_message(&self) -> String {
-		self.git_wrapper
-			.prev_commit_msg()
-			.unwrap_or_default()
-			.subject()
-			.to_string()
+		self.commit_message("HEAD").unwrap_or_default()
+	}
+
+	/// The scope to suggest for a Conventional Commit, after the staged files
+	pub fn suggested_scope(&self) -> Option<String> {
+		scope_of(&self.git_wrapper.staged_paths().ok()?)
+	}
+
+	pub fn committer(&self) -> Result<Identity> {
+		self.git_wrapper.committer()
 	}
 
 	pub fn commit(&mut self, commit_mode: CommitMode) -> Result<()> {
-		match commit_mode {
+		let pending = self.prepare(commit_mode)?;
+		self.finish(&pending)
+	}
+
+	/// Everything but creating the commit, left to `finish` once previewed
+	pub fn prepare(&mut self, commit_mode: CommitMode) -> Result<Pending> {
+		let (amend, driver, cleanup, comment) = match commit_mode {
 			CommitMode::WithoutEditor {
 				message,
 				authors,
 				amend,
+				driver,
+				cleanup,
 			} => {
-				self.pre(&CommitMessage::new(message, authors))?;
-				self.run_commit(amend)
+				let message = self.new_message(message, authors);
+				let comment = self.comment.resolve(&message.to_string());
+				let cleanup = cleanup.unwrap_or(self.cleanup).resolve(false);
+				self.pre(&message)?;
+				self.lint(cleanup, &comment)?;
+				(amend, driver, cleanup, comment)
 			}
 			CommitMode::WithEditor {
 				message,
 				authors,
 				amend,
+				driver,
+				cleanup,
+				verbose,
 			} => {
-				self.pre(&CommitMessage::new(message.unwrap_or_default(), authors))?;
-				self.editor()?;
-				self.run_commit(amend)
+				let verbose = verbose || self.verbose;
+				let cleanup = cleanup.unwrap_or(self.cleanup).resolve(true);
+				let given = message.or(self.template.as_deref()).unwrap_or_default();
+				let from_scratch = given.trim().is_empty();
+				let message = self.new_message(given, authors);
+				let comment = self.comment.resolve(&message.to_string());
+				self.pre(&message)?;
+				self.editor(cleanup, verbose, &comment)?;
+				// Like git, the diff below the scissors line is left out whatever the cleanup
+				if verbose && matches!(cleanup, Cleanup::Whitespace | Cleanup::Verbatim) {
+					self.cut_diff(&comment)?;
+				}
+				// Like an untouched template, the ticket prefix alone doesn't make a message
+				if from_scratch && !message.has_no_content() {
+					let edited = self.git_wrapper.pending_message(cleanup, &comment)?;
+					if edited.has_same_content(&message) {
+						return Err(GitError::LibGit("Commit message cannot be empty".to_string()).into());
+					}
+				}
+				self.lint(cleanup, &comment)?;
+				(amend, driver, cleanup, comment)
 			}
+		};
+		// Run now for the preview to show the message as it will be committed
+		self.hook_runner.run_commit_msg()?;
+		self.check_conventional(cleanup, &comment)?;
+		Ok(Pending {
+			amend,
+			driver,
+			cleanup,
+			comment,
+		})
+	}
+
+	pub fn preview(&self, pending: &Pending) -> Result<Preview> {
+		let committer = self.git_wrapper.committer()?;
+		Ok(Preview {
+			message: self.git_wrapper.pending_message(pending.cleanup, &pending.comment)?,
+			author: pending.driver.clone().unwrap_or_else(|| committer.clone()),
+			committer,
+			staged: self.git_wrapper.staged_summary()?,
+			amend: pending.amend,
+		})
+	}
+
+	pub fn finish(&self, pending: &Pending) -> Result<()> {
+		let driver = pending.driver.clone();
+		if pending.amend {
+			self.git_wrapper.amend(driver, pending.cleanup, &pending.comment)
+		} else {
+			self.git_wrapper.commit(driver, pending.cleanup, &pending.comment)
 		}
 	}
 
@@ -63,18 +227,70 @@ impl<G: GitWrapper, H: HookRunner, E: Editor, W: Writer> GitService<G, H, E, W>
 			.overwrite(&PathBuf::from(&self.editmsg_path), &body.to_string())
 	}
 
-	fn editor(&mut self) -> Result<()> {
-		let status = self.git_wrapper.formatted_status()?;
-		self.file_writer.append(&PathBuf::from(&self.editmsg_path), &status)?;
-		self.editmsg_editor.open(&self.editmsg_path)
+	fn new_message(&self, message: &str, mut authors: Vec<String>) -> CommitMessage {
+		let message = match &self.branch_ticket {
+			Some(ticket) => ticket.reference_in(message, &mut authors),
+			None => message.to_string(),
+		};
+		CommitMessage::new(&message, authors, &self.trailer_rules)
 	}
 
-	fn run_commit(&self, amend: bool) -> Result<()> {
-		self.hook_runner.run_commit_msg()?;
-		if amend {
-			self.git_wrapper.amend()
-		} else {
-			self.git_wrapper.commit()
+	/// An empty message is left for the commit itself to report
+	fn check_conventional(&self, cleanup: Cleanup, comment: &str) -> Result<()> {
+		let Some(conventional) = &self.conventional else {
+			return Ok(());
+		};
+		let message = self.git_wrapper.pending_message(cleanup, comment)?;
+		if message.has_no_content() {
+			return Ok(());
 		}
+		conventional.validate(&message)
+	}
+
+	fn lint(&mut self, cleanup: Cleanup, comment: &str) -> Result<()> {
+		self.rejected = None;
+		if self.lint_rules.is_off() {
+			return Ok(());
+		}
+		let message = self.git_wrapper.pending_message(cleanup, comment)?;
+		let violations = self.lint_rules.check(&message);
+		if violations.is_empty() {
+			return Ok(());
+		}
+		self.rejected = Some(message);
+		let violations: Vec<String> = violations.iter().map(|violation| format!("- {violation}")).collect();
+		Err(GitError::Lint(violations.join("\n")).into())
+	}
+
+	/// The edited message as is, but for what follows the scissors line
+	fn cut_diff(&self, comment: &str) -> Result<()> {
+		let edited = self
+			.git_wrapper
+			.pending_message(Cleanup::Verbatim, comment)?
+			.to_string();
+		self.file_writer
+			.overwrite(&PathBuf::from(&self.editmsg_path), cut_at_scissors(&edited, comment))
+	}
+
+	fn editor(&mut self, cleanup: Cleanup, verbose: bool, comment: &str) -> Result<()> {
+		let mut status = self.git_wrapper.formatted_status(cleanup, comment)?;
+		let cut_line = format!(
+			"{}\n{comment} Do not modify or remove the line above.\n{comment} Everything below it will be ignored.",
+			scissors(comment)
+		);
+		if cleanup == Cleanup::Scissors {
+			status = format!("\n\n{cut_line}{status}");
+		}
+		if verbose {
+			let diff = self.git_wrapper.staged_diff()?;
+			// A single scissors line is enough, whatever is below it is ignored anyway
+			status = if cleanup == Cleanup::Scissors {
+				format!("{status}{diff}")
+			} else {
+				format!("{status}{cut_line}\n{diff}")
+			};
+		}
+		self.file_writer.append(&PathBuf::from(&self.editmsg_path), &status)?;
+		self.editmsg_editor.open(&self.editmsg_path)
 	}
 }
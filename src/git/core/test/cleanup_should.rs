@@ -0,0 +1,44 @@
+use crate::git::cleanup::Cleanup;
+use git2::Config;
+use parameterized::parameterized;
+use uuid::Uuid;
+
+#[parameterized(mode = {"strip", "Whitespace", "verbatim", "scissors", "default"}, expected = {
+	Cleanup::Strip, Cleanup::Whitespace, Cleanup::Verbatim, Cleanup::Scissors, Cleanup::Default
+})]
+fn parse_every_git_mode(mode: &str, expected: Cleanup) {
+	assert_eq!(Cleanup::parse(mode), Some(expected));
+}
+
+#[test]
+fn not_parse_unknown_modes() {
+	assert_eq!(Cleanup::parse("tidy"), None);
+}
+
+#[test]
+fn only_strip_comments_by_default_when_edited() {
+	assert_eq!(Cleanup::Default.resolve(true), Cleanup::Strip);
+	assert_eq!(Cleanup::Default.resolve(false), Cleanup::Whitespace);
+}
+
+#[test]
+fn only_cut_at_scissors_when_edited() {
+	assert_eq!(Cleanup::Scissors.resolve(true), Cleanup::Scissors);
+	assert_eq!(Cleanup::Scissors.resolve(false), Cleanup::Whitespace);
+}
+
+#[test]
+fn load_from_git_config() -> crate::Result<()> {
+	let path = format!("/tmp/coa/cleanup/{}", Uuid::new_v4());
+	std::fs::create_dir_all("/tmp/coa/cleanup")?;
+	let mut config = Config::open(std::path::Path::new(&path))?;
+	let unset = Cleanup::from(&config);
+	config.set_str("commit.cleanup", "verbatim")?;
+
+	let set = Cleanup::from(&config);
+
+	std::fs::remove_file(path).ok();
+	assert_eq!(unset, Cleanup::Default);
+	assert_eq!(set, Cleanup::Verbatim);
+	Ok(())
+}
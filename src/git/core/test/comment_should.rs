@@ -0,0 +1,22 @@
+use crate::git::comment::CommentString;
+
+#[test]
+fn default_to_a_hash() {
+	assert_eq!(CommentString::from(None).resolve(""), "#");
+}
+
+#[test]
+fn use_the_configured_string() {
+	assert_eq!(
+		CommentString::from(Some(";".to_string())).resolve("; not a comment"),
+		";"
+	);
+}
+
+#[test]
+fn pick_a_character_no_line_starts_with_in_auto_mode() {
+	let comment = CommentString::from(Some("auto".to_string()));
+
+	assert_eq!(comment.resolve("subject\n\n# Heading\n; and more"), "@");
+	assert_eq!(comment.resolve("subject"), "#");
+}
@@ -0,0 +1,75 @@
+use crate::git::cleanup::{scissors, Cleanup};
+use crate::git::core::commit_message::CommitMessage;
+use crate::git::core::trailer_rules::TrailerRules;
+
+const MESSAGE: &str = "subject\n\n#123 is fixed  \n\n\n# a comment";
+
+fn cleaned_up(message: &str, cleanup: Cleanup) -> String {
+	CommitMessage::from_with(message, &TrailerRules::default(), cleanup, "#").to_string()
+}
+
+#[test]
+fn strip_comments_and_whitespace() {
+	assert_eq!(cleaned_up(MESSAGE, Cleanup::Strip), "subject");
+}
+
+#[test]
+fn only_strip_whitespace() {
+	assert_eq!(
+		cleaned_up(MESSAGE, Cleanup::Whitespace),
+		"subject\n\n#123 is fixed\n\n# a comment"
+	);
+}
+
+#[test]
+fn keep_the_message_verbatim() {
+	assert_eq!(cleaned_up(MESSAGE, Cleanup::Verbatim), MESSAGE);
+}
+
+#[test]
+fn cut_everything_from_the_scissors_line() {
+	let message = format!(
+		"subject\n\n#123 is fixed\n{}\n# On branch main\ndiff --git a/x b/x",
+		scissors("#")
+	);
+
+	assert_eq!(cleaned_up(&message, Cleanup::Scissors), "subject\n\n#123 is fixed");
+}
+
+#[test]
+fn only_strip_lines_starting_with_the_comment_string() {
+	let message = "subject\n\n# Heading\n; a comment";
+
+	assert_eq!(
+		CommitMessage::from_with(message, &TrailerRules::default(), Cleanup::Strip, ";").to_string(),
+		"subject\n\n# Heading"
+	);
+}
+
+#[test]
+fn only_strip_comments_starting_the_line() {
+	let message = "subject\n\n    # shell prompt in an example\n# a comment";
+
+	assert_eq!(
+		cleaned_up(message, Cleanup::Strip),
+		"subject\n\n    # shell prompt in an example"
+	);
+}
+
+#[test]
+fn cut_at_the_scissors_line_when_stripping() {
+	let message = format!("subject\n{}\n+added line", scissors(";"));
+
+	assert_eq!(
+		CommitMessage::from_with(&message, &TrailerRules::default(), Cleanup::Strip, ";").to_string(),
+		"subject"
+	);
+}
+
+#[test]
+fn keep_a_scissors_line_verbatim() {
+	let message = format!("subject\n\n{}\nbelow", scissors("#"));
+
+	assert_eq!(cleaned_up(&message, Cleanup::Verbatim), message);
+	assert_eq!(cleaned_up(&message, Cleanup::Whitespace), message);
+}
@@ -1,5 +1,6 @@
 use super::helper::*;
 use crate::git::core::commit_message::CommitMessage;
+use crate::git::core::trailer_rules::TrailerRules;
 
 fn test_cases() -> Vec<TestCase> {
 	vec![
@@ -7,19 +8,19 @@ fn test_cases() -> Vec<TestCase> {
 			.subject(A_SUBJECT)
 			.body(A_LINE)
 			.authors(&[AN_AUTHOR])
-			.expected(&format!("{A_SUBJECT}\n\n{A_LINE}\n\n\n{AN_AUTHOR}"))
+			.expected(&format!("{A_SUBJECT}\n\n{A_LINE}\n\n{AN_AUTHOR}"))
 			.create(),
 		TestCase::build_for("too many newlines")
 			.subject(A_SUBJECT)
 			.body(&format!("\n{A_LINE}\n\n\n{A_LINE}\n"))
 			.authors(&[A_LINE, A_LINE])
-			.expected(&format!("{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n\n{A_LINE}\n{A_LINE}"))
+			.expected(&format!("{A_SUBJECT}\n\n{A_LINE}\n\n{A_LINE}\n\n{A_LINE}\n{A_LINE}"))
 			.create(),
 		TestCase::build_for("no body")
 			.subject(A_SUBJECT)
 			.body("")
 			.authors(&[A_LINE, A_LINE])
-			.expected(&format!("{A_SUBJECT}\n\n\n{A_LINE}\n{A_LINE}"))
+			.expected(&format!("{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}"))
 			.create(),
 		TestCase::build_for("no authors")
 			.subject(A_SUBJECT)
@@ -37,7 +38,7 @@ fn test_cases() -> Vec<TestCase> {
 			.subject("")
 			.body("")
 			.authors(&[AN_AUTHOR, AN_AUTHOR])
-			.expected(&format!("\n\n\n{AN_AUTHOR}\n{AN_AUTHOR}"))
+			.expected(&format!("\n\n{AN_AUTHOR}\n{AN_AUTHOR}"))
 			.create(),
 		TestCase::build_for("empty")
 			.subject("")
@@ -66,6 +67,7 @@ fn given_a_correctly_formatted_commit_message() {
 		let actual = CommitMessage::new(
 			&format!("{}\n\n{}", t.message.subject, t.message.body),
 			t.message.authors.clone(),
+			&TrailerRules::default(),
 		)
 		.to_string();
 		assert_eq!(t.expected, actual, "{}", t.name);
@@ -75,8 +77,12 @@ fn given_a_correctly_formatted_commit_message() {
 #[test]
 fn given_a_poorly_formatted_commit_message() {
 	for t in test_cases() {
-		let actual =
-			CommitMessage::new(&format!("{}\n{}", t.message.subject, t.message.body), t.message.authors).to_string();
+		let actual = CommitMessage::new(
+			&format!("{}\n{}", t.message.subject, t.message.body),
+			t.message.authors,
+			&TrailerRules::default(),
+		)
+		.to_string();
 		assert_eq!(t.expected, actual, "{}", t.name);
 	}
 }
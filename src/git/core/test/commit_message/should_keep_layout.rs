@@ -0,0 +1,33 @@
+use crate::git::core::commit_message::CommitMessage;
+use crate::git::core::trailer_rules::TrailerRules;
+
+#[test]
+fn keep_paragraph_breaks() {
+	let message = CommitMessage::from("subject\n\nfirst paragraph\nstill first\n\nsecond paragraph");
+
+	assert_eq!(
+		message.to_string(),
+		"subject\n\nfirst paragraph\nstill first\n\nsecond paragraph"
+	);
+}
+
+#[test]
+fn keep_leading_indentation() {
+	let message = CommitMessage::new(
+		"subject\n\nsteps:\n  - one\n  - two\n\n    let snippet = 42;",
+		vec![],
+		&TrailerRules::default(),
+	);
+
+	assert_eq!(
+		message.to_string(),
+		"subject\n\nsteps:\n  - one\n  - two\n\n    let snippet = 42;"
+	);
+}
+
+#[test]
+fn strip_trailing_whitespace_and_collapse_blank_lines() {
+	let message = CommitMessage::from("subject  \n\n\n\nbody \t\n \n\t\n\nmore\n\n\n");
+
+	assert_eq!(message.to_string(), "subject\n\nbody\n\nmore");
+}
@@ -8,7 +8,7 @@ fn test_cases() -> Vec<TestCase> {
 			.body(&format!("{A_LINE}\n{A_LINE}"))
 			.authors(&[AN_AUTHOR, AN_AUTHOR])
 			.expected(&format!(
-				"{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
+				"{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
 			))
 			.create(),
 		TestCase::build_for("too many newlines")
@@ -16,14 +16,14 @@ fn test_cases() -> Vec<TestCase> {
 			.body(&format!("\n{A_LINE}\n\n\n{A_LINE}\n"))
 			.authors(&[AN_AUTHOR, AN_AUTHOR])
 			.expected(&format!(
-				"{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
+				"{A_SUBJECT}\n\n{A_LINE}\n\n{A_LINE}\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
 			))
 			.create(),
 		TestCase::build_for("no body")
 			.subject(A_SUBJECT)
 			.body("")
 			.authors(&[AN_AUTHOR, AN_AUTHOR])
-			.expected(&format!("{A_SUBJECT}\n\n\n{AN_AUTHOR}\n{AN_AUTHOR}"))
+			.expected(&format!("{A_SUBJECT}\n\n{AN_AUTHOR}\n{AN_AUTHOR}"))
 			.create(),
 		TestCase::build_for("no authors")
 			.subject(A_SUBJECT)
@@ -48,7 +48,7 @@ fn test_cases() -> Vec<TestCase> {
 			.body(&format!("{A_LINE}\n{COMMENT}\n{A_LINE}"))
 			.authors(&[AN_AUTHOR, AN_AUTHOR])
 			.expected(&format!(
-				"{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
+				"{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
 			))
 			.create(),
 		TestCase::build_for("whitespaces")
@@ -56,7 +56,7 @@ fn test_cases() -> Vec<TestCase> {
 			.body(&format!("{A_LINE}{WHITESPACE}\n{A_LINE}"))
 			.authors(&[AN_AUTHOR, AN_AUTHOR])
 			.expected(&format!(
-				"{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
+				"{A_SUBJECT}\n\n{A_LINE}\n{A_LINE}\n\n{AN_AUTHOR}\n{AN_AUTHOR}"
 			))
 			.create(),
 	]
@@ -0,0 +1,113 @@
+use crate::git::core::commit_message::CommitMessage;
+use crate::git::core::trailer_rules::TrailerRules;
+
+const CO_AUTHOR: &str = "Co-authored-by: Bob <bob@x>";
+const SIGNED_OFF: &str = "Signed-off-by: Alice <alice@x>";
+const REVIEWED: &str = "Reviewed-by: Carol <carol@x>";
+
+#[test]
+fn keep_other_trailers_in_order_along_with_co_authors() {
+	let message = CommitMessage::from(&format!("subject\n\nbody\n\n{SIGNED_OFF}\n{CO_AUTHOR}\n{REVIEWED}"));
+
+	assert_eq!(
+		message.to_string(),
+		format!("subject\n\nbody\n\n{SIGNED_OFF}\n{CO_AUTHOR}\n{REVIEWED}")
+	);
+	assert_eq!(message.authors(), [CO_AUTHOR]);
+}
+
+#[test]
+fn merge_co_authors_into_the_trailer_block_of_a_new_message() {
+	let message = CommitMessage::new(
+		&format!("subject\n\nbody\n\n{SIGNED_OFF}"),
+		vec![CO_AUTHOR.to_string()],
+		&TrailerRules::default(),
+	);
+
+	assert_eq!(
+		message.to_string(),
+		format!("subject\n\nbody\n\n{SIGNED_OFF}\n{CO_AUTHOR}")
+	);
+}
+
+#[test]
+fn not_duplicate_co_authors_already_in_a_new_message() {
+	let message = CommitMessage::new(
+		&format!("subject\n\n{CO_AUTHOR}"),
+		vec![CO_AUTHOR.to_string()],
+		&TrailerRules::default(),
+	);
+
+	assert_eq!(message.authors(), [CO_AUTHOR]);
+}
+
+#[test]
+fn keep_continuation_lines() {
+	let message = CommitMessage::from(&format!("subject\n\nSee-also: a very\n  long value\n{SIGNED_OFF}"));
+
+	assert_eq!(
+		message.to_string(),
+		format!("subject\n\nSee-also: a very\n  long value\n{SIGNED_OFF}")
+	);
+}
+
+#[test]
+fn leave_a_last_paragraph_with_other_lines_in_the_body() {
+	let message = CommitMessage::from("subject\n\nFixes: a bug\nthat was there");
+
+	assert_eq!(message.to_string(), "subject\n\nFixes: a bug\nthat was there");
+}
+
+#[test]
+fn not_take_urls_as_trailers() {
+	let message = CommitMessage::from("subject\n\nhttps://example.com");
+
+	assert_eq!(message.to_string(), "subject\n\nhttps://example.com");
+}
+
+#[test]
+fn never_take_the_subject_as_a_trailer() {
+	let message = CommitMessage::from("fix: something\nRefs: 123");
+
+	assert_eq!(message.subject(), "fix: something");
+	assert_eq!(message.to_string(), "fix: something\n\nRefs: 123");
+}
+
+#[test]
+fn normalize_co_author_prefixes_regardless_of_case() {
+	let message = CommitMessage::from("subject\n\nCo-Authored-By: Bob <bob@x>\nco-authored-by : Dan <dan@x>");
+
+	assert_eq!(
+		message.to_string(),
+		format!("subject\n\n{CO_AUTHOR}\nCo-authored-by: Dan <dan@x>")
+	);
+}
+
+#[test]
+fn recognize_configured_equivalent_prefixes() {
+	let message = CommitMessage::from(&format!("subject\n\nCoauthored-by: Bob <bob@x>\n{SIGNED_OFF}"));
+
+	assert_eq!(message.authors(), [CO_AUTHOR]);
+}
+
+#[test]
+fn not_duplicate_co_authors_spelled_differently_in_a_new_message() {
+	let message = CommitMessage::new(
+		"subject\n\nCo-Authored-By: Bob <bob@x>",
+		vec![CO_AUTHOR.to_string()],
+		&TrailerRules::default(),
+	);
+
+	assert_eq!(message.to_string(), format!("subject\n\n{CO_AUTHOR}"));
+}
+
+#[test]
+fn update_co_authors_signed_with_another_template() {
+	let message = CommitMessage::new(
+		&format!("subject\n\nCo-authored-by: Bob (@bob) <BOB@x>\n{SIGNED_OFF}"),
+		vec![CO_AUTHOR.to_string()],
+		&TrailerRules::default(),
+	);
+
+	assert_eq!(message.to_string(), format!("subject\n\n{CO_AUTHOR}\n{SIGNED_OFF}"));
+}
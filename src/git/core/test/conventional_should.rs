@@ -0,0 +1,79 @@
+use crate::error::{assert_error_contains_msg, assert_error_type};
+use crate::git::conventional::{scope_of, Conventional, Subject};
+use crate::git::err::GitError;
+use crate::git::CommitMessage;
+use parameterized::parameterized;
+
+#[parameterized(subject = {
+	"feat: add login", "fix(api): handle nulls", "refactor!: drop v1", "feat(ui)!: new layout", "build(deps-dev): bump"
+})]
+fn parse_and_display_conventional_subjects(subject: &str) {
+	let parsed = Subject::parse(subject);
+
+	assert!(matches!(parsed, Some(parsed) if parsed.to_string() == subject));
+}
+
+#[parameterized(subject = {
+	"add login", "feat:add login", "feat(): add login", "feat(api: add login", "feat: ", "fe at: add login"
+})]
+fn not_parse_other_subjects(subject: &str) {
+	assert_eq!(Subject::parse(subject), None);
+}
+
+#[test]
+fn assemble_the_subject_leaving_out_an_empty_scope() {
+	assert_eq!(
+		Subject::new("fix", Some(" "), false, " a bug ").to_string(),
+		"fix: a bug"
+	);
+	assert_eq!(
+		Subject::new("fix", Some("api"), true, "a bug").to_string(),
+		"fix(api)!: a bug"
+	);
+}
+
+#[test]
+fn describe_breaking_changes_in_a_footer() {
+	let subject = Subject::new("feat", None, true, "new api");
+
+	assert_eq!(
+		subject.message(Some("v1 is gone")),
+		"feat!: new api\n\nBREAKING CHANGE: v1 is gone"
+	);
+	assert_eq!(subject.message(Some(" ")), "feat!: new api");
+}
+
+#[test]
+fn accept_known_types() {
+	let conventional = Conventional::new(vec!["feat".to_string(), "fix".to_string()]);
+
+	assert!(conventional.validate(&CommitMessage::from("fix(api): a bug")).is_ok());
+}
+
+#[parameterized(subject = { "ABC-1: feat: add login", "[ABC-1] fix(api): a bug" })]
+fn look_past_a_leading_ticket_reference(subject: &str) {
+	let conventional = Conventional::new(vec!["feat".to_string(), "fix".to_string()]);
+
+	assert!(conventional.validate(&CommitMessage::from(subject)).is_ok());
+}
+
+#[test]
+fn reject_unknown_types() {
+	let conventional = Conventional::new(vec!["feat".to_string(), "fix".to_string()]);
+
+	let result = conventional.validate(&CommitMessage::from("wip: a bug"));
+
+	assert_error_type(&result, &GitError::Conventional(String::new()));
+	assert_error_contains_msg(&result, "Unknown commit type wip, expected one of feat, fix");
+}
+
+#[parameterized(paths = {
+	&["src/git/a.rs", "src/git/core/b.rs"], &["src/git/a.rs", "src/ui/b.rs"], &["src/a.rs"], &["a.rs", "src/b.rs"], &[]
+}, scope = {
+	Some("git"), Some("src"), Some("src"), None, None
+})]
+fn suggest_the_deepest_common_directory_as_scope(paths: &[&str], scope: Option<&str>) {
+	let paths: Vec<String> = paths.iter().map(ToString::to_string).collect();
+
+	assert_eq!(scope_of(&paths).as_deref(), scope);
+}
@@ -0,0 +1,155 @@
+use crate::git::lint::LintRules;
+use crate::git::CommitMessage;
+use parameterized::parameterized;
+
+#[test]
+fn find_nothing_wrong_when_off() {
+	let message = CommitMessage::from("Added a very long subject that ends with a period.");
+
+	assert!(LintRules::default().is_off());
+	assert!(LintRules::default().check(&message).is_empty());
+}
+
+#[test]
+fn limit_the_subject_length() {
+	let rules = LintRules {
+		subject_max_length: 10,
+		..LintRules::default()
+	};
+
+	assert!(rules.check(&CommitMessage::from("Add login")).is_empty());
+	assert_eq!(
+		rules.check(&CommitMessage::from("Add the login page")),
+		["Subject is 18 characters long, 10 at most"]
+	);
+}
+
+#[test]
+fn reject_a_trailing_period() {
+	let rules = LintRules {
+		subject_no_period: true,
+		..LintRules::default()
+	};
+
+	assert_eq!(
+		rules.check(&CommitMessage::from("Add login.")),
+		["Subject ends with a period"]
+	);
+}
+
+#[parameterized(subject = {
+	"Add login", "fix(api): handle nulls", "Process the queue", "Focus the input", "Update deps",
+	"Bring back the menu", "Embed the font", "Seed the database", "Feed the cache"
+})]
+fn accept_imperative_subjects(subject: &str) {
+	let rules = LintRules {
+		imperative_mood: true,
+		..LintRules::default()
+	};
+
+	assert!(rules.check(&CommitMessage::from(subject)).is_empty());
+}
+
+#[parameterized(subject = { "Added login", "fix(api): handles nulls", "Adding the queue", "ABC-1: Added login" })]
+fn reject_other_moods(subject: &str) {
+	let rules = LintRules {
+		imperative_mood: true,
+		..LintRules::default()
+	};
+
+	assert_eq!(rules.check(&CommitMessage::from(subject)).len(), 1);
+}
+
+#[parameterized(subject = {
+	"ABC-123: Add login", "[ABC-123] Add login", "feat: ABC-123 add login", "ABC-123: feat: add login"
+})]
+fn look_past_a_leading_ticket_reference_for_the_mood(subject: &str) {
+	let rules = LintRules {
+		imperative_mood: true,
+		..LintRules::default()
+	};
+
+	assert!(rules.check(&CommitMessage::from(subject)).is_empty());
+}
+
+#[test]
+fn wrap_the_body_but_links() {
+	let rules = LintRules {
+		body_max_width: 20,
+		..LintRules::default()
+	};
+	let message = CommitMessage::from(
+		"Add login\n\nthis line is way too wide to read\nhttps://example.com/a/very/long/link\nshort",
+	);
+
+	assert_eq!(rules.check(&message), ["Body line is 33 characters wide, 20 at most"]);
+}
+
+#[test]
+fn require_a_ticket_anywhere() {
+	let rules = LintRules {
+		require_ticket: true,
+		..LintRules::default()
+	};
+
+	assert!(rules.check(&CommitMessage::from("Add login\n\nRefs: ABC-1")).is_empty());
+	assert_eq!(
+		rules.check(&CommitMessage::from("Add login")),
+		["No ticket reference (e.g. ABC-123)"]
+	);
+}
+
+#[test]
+fn not_take_emails_or_words_for_tickets() {
+	let rules = LintRules {
+		require_ticket: true,
+		..LintRules::default()
+	};
+	let message = CommitMessage::from("Decode utf-8\n\nCo-authored-by: Dev <dev-42@corp.com>");
+
+	assert_eq!(rules.check(&message), ["No ticket reference (e.g. ABC-123)"]);
+}
+
+#[test]
+fn require_co_authors() {
+	let rules = LintRules {
+		min_co_authors: 2,
+		..LintRules::default()
+	};
+	let message = CommitMessage::from("Add login\n\nCo-authored-by: John <j@x>");
+
+	assert_eq!(rules.check(&message), ["1 co-author(s), 2 at least on this branch"]);
+}
+
+#[test]
+fn reject_references_disagreeing_with_the_branch() {
+	let rules = LintRules {
+		branch_ticket: Some("ABC-1".to_string()),
+		..LintRules::default()
+	};
+
+	assert!(rules.check(&CommitMessage::from("Add login")).is_empty());
+	assert!(rules.check(&CommitMessage::from("ABC-1: Add login")).is_empty());
+	assert_eq!(
+		rules.check(&CommitMessage::from("ABC-2: Add login")),
+		["References ABC-2 while the branch is about ABC-1"]
+	);
+}
+
+#[test]
+fn tell_the_checks_rewording_the_subject_or_selecting_co_authors_can_fix() {
+	let rules = LintRules {
+		subject_no_period: true,
+		body_max_width: 5,
+		min_co_authors: 1,
+		..LintRules::default()
+	};
+	let message = CommitMessage::from("Add login.\n\ntoo wide a body");
+
+	assert_eq!(rules.check(&message).len(), 3);
+	assert_eq!(rules.check_subject(&message), ["Subject ends with a period"]);
+	assert_eq!(
+		rules.check_co_authors(&message).as_deref(),
+		Some("0 co-author(s), 1 at least on this branch")
+	);
+}
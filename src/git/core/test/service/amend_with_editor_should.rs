@@ -35,7 +35,7 @@ fn succeed() {
 	mock_git_wrapper
 		.expect_formatted_status()
 		.times(1)
-		.returning(|| Ok(String::new()))
+		.returning(|_, _| Ok(String::new()))
 		.in_sequence(&mut seq);
 	mock_writer
 		.expect_append()
@@ -55,7 +55,7 @@ fn succeed() {
 	mock_git_wrapper
 		.expect_amend()
 		.times(1)
-		.returning(|| Ok(()))
+		.returning(|_, _, _| Ok(()))
 		.in_sequence(&mut seq);
 
 	let result = do_amend(GitService::new(
@@ -74,8 +74,8 @@ fn not_create_new_commit() {
 	mock_git_wrapper
 		.expect_formatted_status()
 		.times(1)
-		.returning(|| Ok(String::new()));
-	mock_git_wrapper.expect_amend().times(1).returning(|| Ok(()));
+		.returning(|_, _| Ok(String::new()));
+	mock_git_wrapper.expect_amend().times(1).returning(|_, _, _| Ok(()));
 	mock_git_wrapper.expect_commit().times(0);
 
 	let result = do_amend(GitService::new(
@@ -128,7 +128,7 @@ fn add_status_to_editmsg_file() {
 	mock_git_wrapper
 		.expect_formatted_status()
 		.times(1)
-		.returning(move || Ok(String::from(status)))
+		.returning(move |_, _| Ok(String::from(status)))
 		.in_sequence(&mut seq);
 	mock_writer
 		.expect_append()
@@ -136,7 +136,7 @@ fn add_status_to_editmsg_file() {
 		.with(always(), eq(String::from(status)))
 		.returning(|_, _| Ok(()))
 		.in_sequence(&mut seq);
-	mock_git_wrapper.expect_amend().returning(|| Ok(()));
+	mock_git_wrapper.expect_amend().returning(|_, _, _| Ok(()));
 
 	let result = do_amend(GitService::new(
 		mock_git_wrapper,
@@ -186,7 +186,7 @@ fn stop_and_report_add_status_to_editmsg_error() {
 	mock_writer.expect_overwrite().times(1).returning(|_, _| Ok(()));
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(move || Err(ERR_MSG.into()));
+		.returning(move |_, _| Err(ERR_MSG.into()));
 	mock_writer.expect_append().times(0);
 	mock_editor.expect_open().times(0);
 	mock_hook_runner.expect_run_commit_msg().times(0);
@@ -211,7 +211,7 @@ fn stop_and_report_when_editor_cannot_be_opened() {
 	mock_hook_runner.expect_run_pre_commit().returning(|| Ok(()));
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(|| Ok("something".to_string()));
+		.returning(|_, _| Ok("something".to_string()));
 	mock_editor.expect_open().returning(move |_| Err(ERR_MSG.into()));
 	mock_hook_runner.expect_run_commit_msg().times(0);
 	mock_git_wrapper.expect_amend().times(0);
@@ -234,7 +234,7 @@ fn stop_and_report_commit_msg_hook_failure() {
 	mock_hook_runner.expect_run_pre_commit().returning(|| Ok(()));
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(|| Ok("something".to_string()));
+		.returning(|_, _| Ok("something".to_string()));
 	mock_hook_runner
 		.expect_run_commit_msg()
 		.returning(move || Err(ERR_MSG.into()));
@@ -256,8 +256,10 @@ fn report_amend_error() {
 
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(|| Ok("something".to_string()));
-	mock_git_wrapper.expect_amend().returning(move || Err(ERR_MSG.into()));
+		.returning(|_, _| Ok("something".to_string()));
+	mock_git_wrapper
+		.expect_amend()
+		.returning(move |_, _, _| Err(ERR_MSG.into()));
 
 	let result = do_amend(GitService::new(
 		mock_git_wrapper,
@@ -274,5 +276,8 @@ fn do_amend<G: GitWrapper, H: HookRunner, E: Editor, W: Writer>(mut service: Git
 		message: Some(COMMIT_MSG),
 		authors: vec![AUTHOR.to_string()],
 		amend: true,
+		driver: None,
+		cleanup: None,
+		verbose: false,
 	})
 }
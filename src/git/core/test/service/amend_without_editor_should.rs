@@ -39,7 +39,7 @@ fn succeed() {
 	mock_git_wrapper
 		.expect_amend()
 		.times(1)
-		.returning(|| Ok(()))
+		.returning(|_, _, _| Ok(()))
 		.in_sequence(&mut seq);
 
 	let result = do_amend(GitService::new(
@@ -55,7 +55,7 @@ fn succeed() {
 #[test]
 fn not_create_new_commit() {
 	let mut mock_git_wrapper = MockGitWrapper::new();
-	mock_git_wrapper.expect_amend().times(1).returning(|| Ok(()));
+	mock_git_wrapper.expect_amend().times(1).returning(|_, _, _| Ok(()));
 	mock_git_wrapper.expect_commit().times(0);
 
 	let result = do_amend(GitService::new(
@@ -93,7 +93,7 @@ fn not_add_status_to_editmsg_file() {
 	mock_writer.expect_overwrite().times(1).returning(|_, _| Ok(()));
 	mock_git_wrapper.expect_formatted_status().times(0);
 	mock_writer.expect_append().times(0);
-	mock_git_wrapper.expect_amend().returning(|| Ok(()));
+	mock_git_wrapper.expect_amend().returning(|_, _, _| Ok(()));
 
 	let result = do_amend(GitService::new(
 		mock_git_wrapper,
@@ -173,7 +173,9 @@ fn report_amend_error() {
 	let mut mock_git_wrapper = MockGitWrapper::new();
 	let mut mock_writer = MockWriter::new();
 	mock_writer.expect_overwrite().returning(|_, _| Ok(()));
-	mock_git_wrapper.expect_amend().returning(move || Err(ERR_MSG.into()));
+	mock_git_wrapper
+		.expect_amend()
+		.returning(move |_, _, _| Err(ERR_MSG.into()));
 
 	let result = do_amend(GitService::new(
 		mock_git_wrapper,
@@ -190,5 +192,7 @@ fn do_amend<G: GitWrapper, H: HookRunner, E: Editor, W: Writer>(mut service: Git
 		message: COMMIT_MSG,
 		authors: vec![AUTHOR.to_string()],
 		amend: true,
+		driver: None,
+		cleanup: None,
 	})
 }
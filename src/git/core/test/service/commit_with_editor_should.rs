@@ -1,14 +1,18 @@
 use super::mock_helpers::{ok_editor, ok_file_writer, ok_git_wrapper, ok_hook_runner};
 use crate::common::fs::file_writer::{MockWriter, Writer};
 use crate::error::assert_error_contains_msg;
+use crate::git::cleanup::{scissors, Cleanup};
 use crate::git::commit_mode::CommitMode;
-use crate::git::core::commit_message::{GitWrapper, MockGitWrapper};
+use crate::git::core::commit_message::{CommitMessage, GitWrapper, MockGitWrapper};
 use crate::git::core::editor::file_editor::{Editor, MockEditor};
 use crate::git::core::hook::{HookRunner, MockHookRunner};
 use crate::git::core::service::GitService;
+use crate::git::core::trailer_rules::TrailerRules;
+use crate::git::ticket::{BranchTicket, Placement};
 use crate::Result;
 use mockall::predicate::{always, eq};
 use mockall::Sequence;
+use parameterized::parameterized;
 
 const ERR_MSG: &str = "an error";
 const COMMIT_MSG: &str = "a message";
@@ -35,7 +39,7 @@ fn succeed() {
 	mock_git_wrapper
 		.expect_formatted_status()
 		.times(1)
-		.returning(|| Ok(String::new()))
+		.returning(|_, _| Ok(String::new()))
 		.in_sequence(&mut seq);
 	mock_writer
 		.expect_append()
@@ -55,7 +59,7 @@ fn succeed() {
 	mock_git_wrapper
 		.expect_commit()
 		.times(1)
-		.returning(|| Ok(()))
+		.returning(|_, _, _| Ok(()))
 		.in_sequence(&mut seq);
 
 	let result = do_commit(GitService::new(
@@ -74,8 +78,8 @@ fn not_amend() {
 	mock_git_wrapper
 		.expect_formatted_status()
 		.times(1)
-		.returning(|| Ok(String::new()));
-	mock_git_wrapper.expect_commit().times(1).returning(|| Ok(()));
+		.returning(|_, _| Ok(String::new()));
+	mock_git_wrapper.expect_commit().times(1).returning(|_, _, _| Ok(()));
 	mock_git_wrapper.expect_amend().times(0);
 
 	let result = do_commit(GitService::new(
@@ -128,7 +132,7 @@ fn add_status_to_editmsg_file() {
 	mock_git_wrapper
 		.expect_formatted_status()
 		.times(1)
-		.returning(move || Ok(String::from(status)))
+		.returning(move |_, _| Ok(String::from(status)))
 		.in_sequence(&mut seq);
 	mock_writer
 		.expect_append()
@@ -136,7 +140,7 @@ fn add_status_to_editmsg_file() {
 		.with(always(), eq(String::from(status)))
 		.returning(|_, _| Ok(()))
 		.in_sequence(&mut seq);
-	mock_git_wrapper.expect_commit().returning(|| Ok(()));
+	mock_git_wrapper.expect_commit().returning(|_, _, _| Ok(()));
 
 	let result = do_commit(GitService::new(
 		mock_git_wrapper,
@@ -148,6 +152,201 @@ fn add_status_to_editmsg_file() {
 	assert!(result.is_ok());
 }
 
+#[test]
+fn strip_comments_by_default() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_formatted_status()
+		.returning(|_, _| Ok(String::new()));
+	mock_git_wrapper
+		.expect_commit()
+		.withf(|_, cleanup, _| *cleanup == Cleanup::Strip)
+		.times(1)
+		.returning(|_, _, _| Ok(()));
+
+	let result = do_commit(GitService::new(
+		mock_git_wrapper,
+		ok_hook_runner(),
+		ok_editor(),
+		ok_file_writer(),
+	));
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn put_the_status_below_a_scissors_line_when_cutting_there() {
+	let mut mock_writer = MockWriter::new();
+	mock_writer.expect_overwrite().returning(|_, _| Ok(()));
+	mock_writer
+		.expect_append()
+		.times(1)
+		.withf(|_, status| status.contains(&scissors("#")) && status.ends_with("status string"))
+		.returning(|_, _| Ok(()));
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_formatted_status()
+		.returning(|_, _| Ok("status string".to_string()));
+	mock_git_wrapper.expect_commit().returning(|_, _, _| Ok(()));
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), ok_editor(), mock_writer);
+
+	let result = service.commit(CommitMode::WithEditor {
+		message: Some(COMMIT_MSG),
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: Some(Cleanup::Scissors),
+		verbose: false,
+	});
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn put_the_staged_diff_below_a_scissors_line_when_verbose() {
+	let mut mock_writer = MockWriter::new();
+	mock_writer.expect_overwrite().returning(|_, _| Ok(()));
+	mock_writer
+		.expect_append()
+		.times(1)
+		.withf(|_, status| status == format!("status\n{}\n# Do not modify or remove the line above.\n# Everything below it will be ignored.\n+a diff", scissors("#")))
+		.returning(|_, _| Ok(()));
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_formatted_status()
+		.returning(|_, _| Ok("status\n".to_string()));
+	mock_git_wrapper
+		.expect_staged_diff()
+		.times(1)
+		.returning(|| Ok("+a diff".to_string()));
+	mock_git_wrapper.expect_commit().returning(|_, _, _| Ok(()));
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), ok_editor(), mock_writer).with_verbose(true);
+
+	let result = service.commit(CommitMode::WithEditor {
+		message: Some(COMMIT_MSG),
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: None,
+		verbose: false,
+	});
+
+	assert!(result.is_ok());
+}
+
+#[parameterized(cleanup = { Cleanup::Whitespace, Cleanup::Verbatim })]
+fn leave_the_staged_diff_out_whatever_the_cleanup_when_verbose(cleanup: Cleanup) {
+	let edited = format!("{COMMIT_MSG}\n\n# kept\n{}\n+a diff\n", scissors("#"));
+	let mut mock_writer = MockWriter::new();
+	let mut seq = Sequence::new();
+	mock_writer
+		.expect_overwrite()
+		.times(1)
+		.returning(|_, _| Ok(()))
+		.in_sequence(&mut seq);
+	mock_writer
+		.expect_append()
+		.times(1)
+		.returning(|_, _| Ok(()))
+		.in_sequence(&mut seq);
+	mock_writer
+		.expect_overwrite()
+		.times(1)
+		.withf(|_, content| content == format!("{COMMIT_MSG}\n\n# kept\n"))
+		.returning(|_, _| Ok(()))
+		.in_sequence(&mut seq);
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_formatted_status()
+		.returning(|_, _| Ok("status\n".to_string()));
+	mock_git_wrapper
+		.expect_staged_diff()
+		.returning(|| Ok("+a diff".to_string()));
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(move |cleanup, comment| {
+			Ok(CommitMessage::from_with(
+				&edited,
+				&TrailerRules::default(),
+				cleanup,
+				comment,
+			))
+		});
+	mock_git_wrapper
+		.expect_commit()
+		.times(1)
+		.withf(move |_, committed, _| *committed == cleanup)
+		.returning(|_, _, _| Ok(()));
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), ok_editor(), mock_writer);
+
+	let result = service.commit(CommitMode::WithEditor {
+		message: Some(COMMIT_MSG),
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: Some(cleanup),
+		verbose: true,
+	});
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn start_from_the_template_without_a_message() {
+	let mut mock_writer = MockWriter::new();
+	mock_writer
+		.expect_overwrite()
+		.times(1)
+		.withf(|_, content| content.starts_with("a template\n\n- [ ] tested") && content.contains(AUTHOR))
+		.returning(|_, _| Ok(()));
+	mock_writer.expect_append().returning(|_, _| Ok(()));
+	let mut service = GitService::new(
+		ok_git_wrapper(String::new()),
+		ok_hook_runner(),
+		ok_editor(),
+		mock_writer,
+	)
+	.with_template(Some("a template\n\n- [ ] tested".to_string()));
+
+	let result = service.commit(CommitMode::WithEditor {
+		message: None,
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: None,
+		verbose: false,
+	});
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn not_commit_the_ticket_prefix_alone() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_formatted_status()
+		.returning(|_, _| Ok(String::new()));
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(|_, _| Ok(CommitMessage::from("ABC-1: ")));
+	mock_git_wrapper.expect_commit().never();
+
+	let mut service =
+		GitService::new(mock_git_wrapper, ok_hook_runner(), ok_editor(), ok_file_writer()).with_branch_ticket(Some(
+			BranchTicket::new("ABC-1", Placement::Prefix("{ticket}: ".to_string())),
+		));
+	let result = service.commit(CommitMode::WithEditor {
+		message: None,
+		authors: vec![],
+		amend: false,
+		driver: None,
+		cleanup: None,
+		verbose: false,
+	});
+
+	assert_error_contains_msg(&result, "Commit message cannot be empty");
+}
+
 #[test]
 fn stop_and_report_pre_commit_hook_failure() {
 	let mut mock_hook_runner = MockHookRunner::new();
@@ -186,7 +385,7 @@ fn stop_and_report_add_status_to_editmsg_error() {
 	mock_writer.expect_overwrite().times(1).returning(|_, _| Ok(()));
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(move || Err(ERR_MSG.into()));
+		.returning(move |_, _| Err(ERR_MSG.into()));
 	mock_writer.expect_append().times(0);
 	mock_editor.expect_open().times(0);
 	mock_hook_runner.expect_run_commit_msg().times(0);
@@ -211,7 +410,7 @@ fn stop_and_report_when_editor_cannot_be_opened() {
 	mock_hook_runner.expect_run_pre_commit().returning(|| Ok(()));
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(|| Ok("something".to_string()));
+		.returning(|_, _| Ok("something".to_string()));
 	mock_editor.expect_open().returning(move |_| Err(ERR_MSG.into()));
 	mock_hook_runner.expect_run_commit_msg().times(0);
 	mock_git_wrapper.expect_commit().times(0);
@@ -234,7 +433,7 @@ fn stop_and_report_commit_msg_hook_failure() {
 	mock_hook_runner.expect_run_pre_commit().returning(|| Ok(()));
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(|| Ok("something".to_string()));
+		.returning(|_, _| Ok("something".to_string()));
 	mock_hook_runner
 		.expect_run_commit_msg()
 		.returning(move || Err(ERR_MSG.into()));
@@ -256,8 +455,10 @@ fn report_commit_error() {
 
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(|| Ok("something".to_string()));
-	mock_git_wrapper.expect_commit().returning(move || Err(ERR_MSG.into()));
+		.returning(|_, _| Ok("something".to_string()));
+	mock_git_wrapper
+		.expect_commit()
+		.returning(move |_, _, _| Err(ERR_MSG.into()));
 
 	let result = do_commit(GitService::new(
 		mock_git_wrapper,
@@ -274,5 +475,8 @@ fn do_commit<G: GitWrapper, H: HookRunner, E: Editor, W: Writer>(mut service: Gi
 		message: Some(COMMIT_MSG),
 		authors: vec![AUTHOR.to_string()],
 		amend: false,
+		driver: None,
+		cleanup: None,
+		verbose: false,
 	})
 }
@@ -1,14 +1,22 @@
 use super::mock_helpers::{ok_git_wrapper, ok_hook_runner};
+use crate::common::conf;
 use crate::common::fs::file_writer::{MockWriter, Writer};
-use crate::error::assert_error_contains_msg;
+use crate::error::{assert_error_contains_msg, assert_error_type};
+use crate::git::cleanup::Cleanup;
 use crate::git::commit_mode::CommitMode;
-use crate::git::core::commit_message::{GitWrapper, MockGitWrapper};
+use crate::git::conventional::Conventional;
+use crate::git::core::commit_message::{CommitMessage, GitWrapper, MockGitWrapper};
 use crate::git::core::editor::file_editor::{Editor, MockEditor};
 use crate::git::core::hook::{HookRunner, MockHookRunner};
 use crate::git::core::service::GitService;
 use crate::git::core::test::service::mock_helpers::ok_file_writer;
+use crate::git::err::GitError;
+use crate::git::identity::Identity;
+use crate::git::lint::LintRules;
+use crate::git::ticket::{BranchTicket, Placement};
 use crate::Result;
 use mockall::Sequence;
+use std::sync::{Arc, Mutex};
 
 const ERR_MSG: &str = "an error";
 const COMMIT_MSG: &str = "a message";
@@ -39,7 +47,7 @@ fn succeed() {
 	mock_git_wrapper
 		.expect_commit()
 		.times(1)
-		.returning(|| Ok(()))
+		.returning(|_, _, _| Ok(()))
 		.in_sequence(&mut seq);
 
 	let result = do_commit(GitService::new(
@@ -55,7 +63,7 @@ fn succeed() {
 #[test]
 fn not_amend() {
 	let mut mock_git_wrapper = MockGitWrapper::new();
-	mock_git_wrapper.expect_commit().times(1).returning(|| Ok(()));
+	mock_git_wrapper.expect_commit().times(1).returning(|_, _, _| Ok(()));
 	mock_git_wrapper.expect_amend().times(0);
 
 	let result = do_commit(GitService::new(
@@ -86,6 +94,138 @@ fn write_commit_msg_and_authors() {
 	assert!(result.is_ok());
 }
 
+#[test]
+fn reference_the_ticket_of_the_branch() {
+	let mut mock_writer = MockWriter::new();
+	mock_writer
+		.expect_overwrite()
+		.withf(|_, param| param.starts_with(&format!("ABC-1: {COMMIT_MSG}")))
+		.returning(|_, _| Ok(()));
+
+	let result = do_commit(
+		GitService::new(
+			ok_git_wrapper(String::new()),
+			ok_hook_runner(),
+			MockEditor::new(),
+			mock_writer,
+		)
+		.with_branch_ticket(Some(BranchTicket::new(
+			"ABC-1",
+			Placement::Prefix("{ticket}: ".to_string()),
+		))),
+	);
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn reference_the_ticket_of_the_branch_in_a_conventional_commit() {
+	// What is written is what git would commit
+	let written = Arc::new(Mutex::new(String::new()));
+	let mut mock_writer = MockWriter::new();
+	let to_write = Arc::clone(&written);
+	mock_writer.expect_overwrite().returning(move |_, message| {
+		*to_write.lock().expect("the lock not to be poisoned") = message.to_string();
+		Ok(())
+	});
+	let mut mock_git_wrapper = ok_git_wrapper(String::new());
+	mock_git_wrapper.expect_pending_message().returning(move |_, _| {
+		Ok(CommitMessage::from(
+			written.lock().expect("the lock not to be poisoned").as_str(),
+		))
+	});
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), mock_writer)
+		.with_conventional(Some(Conventional::new(vec!["feat".to_string()])))
+		.with_lint_rules(LintRules::default())
+		.with_branch_ticket(Some(BranchTicket::new(
+			"ABC-1",
+			Placement::Prefix("{ticket}: ".to_string()),
+		)));
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: "feat: add foo",
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	});
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn pass_the_driver_as_author() {
+	let driver = Identity::from("a driver", "a@driver");
+	let expected = driver.clone();
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_commit()
+		.withf(move |author, _, _| *author == Some(expected.clone()))
+		.times(1)
+		.returning(|_, _, _| Ok(()));
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer());
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: Some(driver),
+		cleanup: None,
+	});
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn leave_creating_the_commit_until_finished() -> Result<()> {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper.expect_commit().times(1).returning(|_, _, _| Ok(()));
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer());
+	let pending = service.prepare(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	})?;
+
+	service.finish(&pending)
+}
+
+#[test]
+fn preview_what_is_about_to_be_committed() -> Result<()> {
+	let driver = Identity::from("a driver", "a@driver");
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_committer()
+		.returning(|| Ok(Identity::from("a committer", "a@committer")));
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(|_, _| Ok(CommitMessage::from("a message\n\nCo-authored-by: an author")));
+	mock_git_wrapper
+		.expect_staged_summary()
+		.returning(|| Ok(vec!["modified:   foo".to_string()]));
+	mock_git_wrapper.expect_commit().times(0);
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer());
+	let pending = service.prepare(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![AUTHOR.to_string()],
+		amend: true,
+		driver: Some(driver.clone()),
+		cleanup: None,
+	})?;
+	let preview = service.preview(&pending)?;
+
+	assert_eq!(preview.message.authors(), ["Co-authored-by: an author"]);
+	assert_eq!(preview.author, driver);
+	assert_eq!(preview.committer, Identity::from("a committer", "a@committer"));
+	assert_eq!(preview.staged, ["modified:   foo"]);
+	assert!(preview.amend);
+	Ok(())
+}
+
 #[test]
 fn not_add_status_to_editmsg_file() {
 	let mut mock_git_wrapper = MockGitWrapper::new();
@@ -93,7 +233,7 @@ fn not_add_status_to_editmsg_file() {
 	mock_writer.expect_overwrite().times(1).returning(|_, _| Ok(()));
 	mock_git_wrapper.expect_formatted_status().times(0);
 	mock_writer.expect_append().times(0);
-	mock_git_wrapper.expect_commit().returning(|| Ok(()));
+	mock_git_wrapper.expect_commit().returning(|_, _, _| Ok(()));
 
 	let result = do_commit(GitService::new(
 		mock_git_wrapper,
@@ -173,7 +313,9 @@ fn report_commit_error() {
 	let mut mock_git_wrapper = MockGitWrapper::new();
 	let mut mock_writer = MockWriter::new();
 	mock_writer.expect_overwrite().returning(|_, _| Ok(()));
-	mock_git_wrapper.expect_commit().returning(move || Err(ERR_MSG.into()));
+	mock_git_wrapper
+		.expect_commit()
+		.returning(move |_, _, _| Err(ERR_MSG.into()));
 
 	let result = do_commit(GitService::new(
 		mock_git_wrapper,
@@ -190,5 +332,198 @@ fn do_commit<G: GitWrapper, H: HookRunner, E: Editor, W: Writer>(mut service: Gi
 		message: COMMIT_MSG,
 		authors: vec![AUTHOR.to_string()],
 		amend: false,
+		driver: None,
+		cleanup: None,
 	})
 }
+
+#[test]
+fn keep_comments_by_default_without_editor() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_commit()
+		.withf(|_, cleanup, _| *cleanup == Cleanup::Whitespace)
+		.times(1)
+		.returning(|_, _, _| Ok(()));
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer());
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	});
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn prefer_the_given_cleanup_over_the_configured_one() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_commit()
+		.withf(|_, cleanup, _| *cleanup == Cleanup::Verbatim)
+		.times(1)
+		.returning(|_, _, _| Ok(()));
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer())
+		.with_cleanup(Cleanup::Strip);
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: Some(Cleanup::Verbatim),
+	});
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn not_commit_a_message_failing_the_checks() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(|_, _| Ok(CommitMessage::from("Added things.")));
+	mock_git_wrapper.expect_commit().never();
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer())
+		.with_lint_rules(LintRules {
+			subject_no_period: true,
+			..LintRules::default()
+		});
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	});
+
+	assert_error_type(&result, &GitError::Lint(String::new()));
+	assert_error_contains_msg(&result, "- Subject ends with a period");
+	assert_eq!(service.rejected_message(), Some(&CommitMessage::from("Added things.")));
+	assert!(!service.lacks_co_authors());
+}
+
+#[test]
+fn offer_to_fix_up_a_message_failing_any_check() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(|_, _| Ok(CommitMessage::from("Add things\n\nfar too wide a body")));
+	mock_git_wrapper.expect_commit().never();
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer())
+		.with_lint_rules(LintRules {
+			body_max_width: 5,
+			..LintRules::default()
+		});
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	});
+
+	assert_error_contains_msg(&result, "- Body line is 19 characters wide, 5 at most");
+	assert_eq!(
+		service.rejected_message(),
+		Some(&CommitMessage::from("Add things\n\nfar too wide a body"))
+	);
+	assert!(!service.lacks_co_authors());
+}
+
+#[test]
+fn tell_when_the_co_authors_are_to_be_fixed_up() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(|_, _| Ok(CommitMessage::from("Add things")));
+	mock_git_wrapper.expect_commit().never();
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer())
+		.with_lint_rules(LintRules {
+			min_co_authors: 1,
+			..LintRules::default()
+		});
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	});
+
+	assert_error_contains_msg(&result, "- 0 co-author(s), 1 at least on this branch");
+	assert_eq!(service.rejected_message(), Some(&CommitMessage::from("Add things")));
+	assert!(service.lacks_co_authors());
+}
+
+#[test]
+fn commit_a_message_passing_the_checks() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(|_, _| Ok(CommitMessage::from("Add things")));
+	mock_git_wrapper.expect_commit().times(1).returning(|_, _, _| Ok(()));
+
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer())
+		.with_lint_rules(LintRules {
+			subject_no_period: true,
+			..LintRules::default()
+		});
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: COMMIT_MSG,
+		authors: vec![],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	});
+
+	assert!(result.is_ok());
+	assert_eq!(service.rejected_message(), None);
+}
+
+#[test]
+fn not_commit_a_non_conventional_message_when_enforced() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(|_, _| Ok(CommitMessage::from("added foo\n\nCo-authored-by: an author")));
+	mock_git_wrapper.expect_commit().never();
+
+	let service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer())
+		.with_conventional(Some(Conventional::new(vec!["feat".to_string()])));
+	let result = do_commit(service);
+
+	assert_error_type(&result, &GitError::Conventional(String::new()));
+	assert_error_contains_msg(&result, "\"added foo\" is not a conventional commit subject");
+}
+
+#[test]
+fn commit_the_mob_handoff_even_when_conventional_commits_are_enforced() {
+	let wip_message = conf::wip_commit_message();
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_pending_message()
+		.returning(move |_, _| Ok(CommitMessage::from(&wip_message)));
+	mock_git_wrapper.expect_commit().times(1).returning(|_, _, _| Ok(()));
+
+	// As set up by the handoff
+	let mut service = GitService::new(mock_git_wrapper, ok_hook_runner(), MockEditor::new(), ok_file_writer())
+		.with_conventional(Some(Conventional::new(vec!["feat".to_string()])))
+		.with_lint_rules(LintRules::default())
+		.without_conventional();
+	let result = service.commit(CommitMode::WithoutEditor {
+		message: &conf::wip_commit_message(),
+		authors: vec![AUTHOR.to_string()],
+		amend: false,
+		driver: None,
+		cleanup: None,
+	});
+
+	assert!(result.is_ok());
+}
@@ -21,9 +21,9 @@ pub fn ok_git_wrapper(status: String) -> MockGitWrapper {
 	let mut mock_git_wrapper = MockGitWrapper::new();
 	mock_git_wrapper
 		.expect_formatted_status()
-		.returning(move || Ok(status.clone()));
-	mock_git_wrapper.expect_commit().returning(|| Ok(()));
-	mock_git_wrapper.expect_amend().returning(|| Ok(()));
+		.returning(move |_, _| Ok(status.clone()));
+	mock_git_wrapper.expect_commit().returning(|_, _, _| Ok(()));
+	mock_git_wrapper.expect_amend().returning(|_, _, _| Ok(()));
 	mock_git_wrapper
 }
 
@@ -2,6 +2,7 @@ use crate::common::fs::file_writer::MockWriter;
 use crate::git::core::commit_message::CommitMessage;
 use crate::git::core::editor::file_editor::MockEditor;
 use crate::git::core::service::GitService;
+use crate::git::core::trailer_rules::TrailerRules;
 use crate::git::core::{commit_message::MockGitWrapper, hook::MockHookRunner};
 
 #[test]
@@ -9,8 +10,8 @@ fn return_prev_message_when_present() {
 	let msg = "a message";
 	let mut mock_git_wrapper = MockGitWrapper::new();
 	mock_git_wrapper
-		.expect_prev_commit_msg()
-		.returning(|| Ok(CommitMessage::new(msg, vec![])));
+		.expect_commit_msg()
+		.returning(|_| Ok(CommitMessage::new(msg, vec![], &TrailerRules::default())));
 	let service = GitService::new(
 		mock_git_wrapper,
 		MockHookRunner::new(),
@@ -26,9 +27,7 @@ fn return_prev_message_when_present() {
 #[test]
 fn return_empty_string_when_prev_message_is_not_present() {
 	let mut mock_git_wrapper = MockGitWrapper::new();
-	mock_git_wrapper
-		.expect_prev_commit_msg()
-		.returning(|| Err("ERR".into()));
+	mock_git_wrapper.expect_commit_msg().returning(|_| Err("ERR".into()));
 	let service = GitService::new(
 		mock_git_wrapper,
 		MockHookRunner::new(),
@@ -40,3 +39,39 @@ fn return_empty_string_when_prev_message_is_not_present() {
 
 	assert_eq!(result, "");
 }
+
+#[test]
+fn give_the_whole_message_of_a_commit_without_co_authors() {
+	let mut mock_git_wrapper = MockGitWrapper::new();
+	mock_git_wrapper
+		.expect_commit_msg()
+		.withf(|rev| rev == "HEAD~2")
+		.returning(|_| {
+			Ok(CommitMessage::from(
+				"a subject\n\na body\n\nCo-authored-by: John <j@x>\nSigned-off-by: Bob <b@x>",
+			))
+		});
+	let service = GitService::new(
+		mock_git_wrapper,
+		MockHookRunner::new(),
+		MockEditor::new(),
+		MockWriter::new(),
+	);
+
+	let result = service.commit_message("HEAD~2");
+
+	assert!(matches!(result, Ok(message) if message == "a subject\n\na body\n\nSigned-off-by: Bob <b@x>"));
+}
+
+#[test]
+fn give_the_template_without_comments_as_message() {
+	let service = GitService::new(
+		MockGitWrapper::new(),
+		MockHookRunner::new(),
+		MockEditor::new(),
+		MockWriter::new(),
+	)
+	.with_template(Some("# what changed?\nsubject\n\n# why?\nbody".to_string()));
+
+	assert_eq!(service.template_message().as_deref(), Some("subject\n\nbody"));
+}
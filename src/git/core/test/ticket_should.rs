@@ -0,0 +1,105 @@
+use crate::error::assert_error_type;
+use crate::git::err::GitError;
+use crate::git::ticket::{BranchTicket, Placement, TicketPattern};
+use crate::Result;
+use parameterized::parameterized;
+
+#[parameterized(text = {
+	"feature/ABC-123-login", "Fixes PROJ-7.", "(OPS-1) hotfix", "Bump to V2-3"
+}, expected = {
+	"ABC-123", "PROJ-7", "OPS-1", "V2-3"
+})]
+fn find_the_first_ticket_reference(text: &str, expected: &str) {
+	assert_eq!(TicketPattern::default().find(text).as_deref(), Some(expected));
+}
+
+#[parameterized(text = {
+	"main", "fix/no-ticket", "v1-2", "123-456", "Decode utf-8", "Hash with sha-256", "ABC-12x", "abc-1", ""
+})]
+fn find_nothing_without_a_reference(text: &str) {
+	assert_eq!(TicketPattern::default().find(text), None);
+}
+
+#[test]
+fn find_every_reference() {
+	assert_eq!(
+		TicketPattern::default().find_all("ABC-1 and abc-2, see XY-3"),
+		["ABC-1", "XY-3"]
+	);
+}
+
+#[parameterized(branch = { "feature/ABC-123-login", "feat/abc-123-add-login" })]
+fn find_the_ticket_of_a_branch_whatever_the_case(branch: &str) {
+	assert_eq!(
+		TicketPattern::default().find_in_branch(branch).as_deref(),
+		Some("ABC-123")
+	);
+}
+
+#[test]
+fn find_the_ticket_of_a_branch_with_a_lower_case_pattern() -> Result<()> {
+	let pattern = TicketPattern::new(r"issue-(\d+)")?;
+
+	assert_eq!(pattern.find_in_branch("fix/issue-42-crash").as_deref(), Some("42"));
+	Ok(())
+}
+
+#[test]
+fn take_the_first_capture_group_as_reference() -> Result<()> {
+	let pattern = TicketPattern::new(r"issue-(\d+)")?;
+
+	assert_eq!(pattern.find("fix/issue-42-crash").as_deref(), Some("42"));
+	Ok(())
+}
+
+#[test]
+fn reject_an_invalid_pattern() {
+	let result = TicketPattern::new("(unclosed");
+
+	assert_error_type(&result, &GitError::TicketPattern(String::new()));
+}
+
+#[test]
+fn prefix_the_subject_with_the_ticket() {
+	let ticket = BranchTicket::new("ABC-1", Placement::Prefix("[{ticket}] ".to_string()));
+	let mut trailers = Vec::new();
+
+	assert_eq!(ticket.reference_in("Add login", &mut trailers), "[ABC-1] Add login");
+	assert!(trailers.is_empty());
+}
+
+#[parameterized(message = { "feat: add login", "feat(auth)!: add login" }, expected = {
+	"feat: ABC-1: add login", "feat(auth)!: ABC-1: add login"
+})]
+fn prefix_the_description_of_a_conventional_commit(message: &str, expected: &str) {
+	let ticket = BranchTicket::new("ABC-1", Placement::Prefix("{ticket}: ".to_string()));
+
+	assert_eq!(ticket.reference_in(message, &mut Vec::new()), expected);
+}
+
+#[test]
+fn add_the_ticket_as_a_trailer() {
+	let ticket = BranchTicket::new("ABC-1", Placement::Trailer("Refs".to_string()));
+	let mut trailers = vec!["Co-authored-by: John <j@x>".to_string()];
+
+	assert_eq!(ticket.reference_in("Add login", &mut trailers), "Add login");
+	assert_eq!(trailers, ["Co-authored-by: John <j@x>", "Refs: ABC-1"]);
+}
+
+#[parameterized(message = { "ABC-1: Add login", "Add login\n\nCloses ABC-1" })]
+fn leave_a_message_already_referencing_the_ticket(message: &str) {
+	let ticket = BranchTicket::new("ABC-1", Placement::Trailer("Refs".to_string()));
+	let mut trailers = Vec::new();
+
+	assert_eq!(ticket.reference_in(message, &mut trailers), message);
+	assert!(trailers.is_empty());
+}
+
+#[parameterized(message = { "ABC-12: Add login", "Add login\n\nFollows XABC-1" })]
+fn reference_the_ticket_when_the_message_only_refers_to_a_longer_one(message: &str) {
+	let ticket = BranchTicket::new("ABC-1", Placement::Trailer("Refs".to_string()));
+	let mut trailers = Vec::new();
+
+	assert_eq!(ticket.reference_in(message, &mut trailers), message);
+	assert_eq!(trailers, ["Refs: ABC-1"]);
+}
@@ -0,0 +1,154 @@
+use crate::git::core::trailer_rules::{IfExists, IfMissing, TrailerRules, Where};
+use git2::Config;
+use uuid::Uuid;
+
+const SIGNED_OFF: &str = "Signed-off-by: Alice <alice@x>";
+const ALICE: &str = "Co-authored-by: Alice <alice@x>";
+const BOB: &str = "Co-authored-by: Bob <bob@x>";
+const REVIEWED: &str = "Reviewed-by: Carol <carol@x>";
+
+fn rules(place: Where, if_exists: IfExists) -> TrailerRules {
+	TrailerRules {
+		place,
+		if_exists,
+		..TrailerRules::default()
+	}
+}
+
+fn lines(trailers: &[&str]) -> Vec<String> {
+	trailers.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn add_at_the_end_by_default() {
+	let merged = TrailerRules::default().merge(&lines(&[SIGNED_OFF]), lines(&[ALICE, BOB]));
+
+	assert_eq!(merged, lines(&[SIGNED_OFF, ALICE, BOB]));
+}
+
+#[test]
+fn add_at_the_start() {
+	let merged = rules(Where::Start, IfExists::Add).merge(&lines(&[SIGNED_OFF]), lines(&[ALICE, BOB]));
+
+	assert_eq!(merged, lines(&[ALICE, BOB, SIGNED_OFF]));
+}
+
+#[test]
+fn add_after_the_last_trailer_with_the_same_key() {
+	let merged = rules(Where::After, IfExists::Add).merge(&lines(&[ALICE, REVIEWED]), lines(&[BOB]));
+
+	assert_eq!(merged, lines(&[ALICE, BOB, REVIEWED]));
+}
+
+#[test]
+fn add_before_the_first_trailer_with_the_same_key() {
+	let merged = rules(Where::Before, IfExists::Add).merge(&lines(&[REVIEWED, BOB]), lines(&[ALICE]));
+
+	assert_eq!(merged, lines(&[REVIEWED, ALICE, BOB]));
+}
+
+#[test]
+fn fall_back_to_the_ends_without_a_trailer_with_the_same_key() {
+	let after = rules(Where::After, IfExists::Add).merge(&lines(&[REVIEWED]), lines(&[ALICE]));
+	let before = rules(Where::Before, IfExists::Add).merge(&lines(&[REVIEWED]), lines(&[ALICE]));
+
+	assert_eq!(after, lines(&[REVIEWED, ALICE]));
+	assert_eq!(before, lines(&[ALICE, REVIEWED]));
+}
+
+#[test]
+fn not_duplicate_existing_trailers_by_default() {
+	let merged = TrailerRules::default().merge(&lines(&[ALICE, REVIEWED]), lines(&[ALICE, BOB]));
+
+	assert_eq!(merged, lines(&[ALICE, REVIEWED, BOB]));
+}
+
+#[test]
+fn only_compare_with_the_neighbor_when_asked_to() {
+	let rules = rules(Where::End, IfExists::AddIfDifferentNeighbor);
+
+	assert_eq!(
+		rules.merge(&lines(&[ALICE, REVIEWED]), lines(&[ALICE])),
+		lines(&[ALICE, REVIEWED, ALICE])
+	);
+	assert_eq!(
+		rules.merge(&lines(&[REVIEWED, ALICE]), lines(&[ALICE])),
+		lines(&[REVIEWED, ALICE])
+	);
+}
+
+#[test]
+fn replace_trailers_with_the_same_key() {
+	let merged = rules(Where::End, IfExists::Replace).merge(&lines(&[ALICE, REVIEWED]), lines(&[BOB]));
+
+	assert_eq!(merged, lines(&[REVIEWED, BOB]));
+}
+
+#[test]
+fn do_nothing_when_the_key_exists_if_asked_to() {
+	let merged = rules(Where::End, IfExists::DoNothing).merge(&lines(&[ALICE]), lines(&[BOB, REVIEWED]));
+
+	assert_eq!(merged, lines(&[ALICE, REVIEWED]));
+}
+
+#[test]
+fn do_nothing_when_the_key_is_missing_if_asked_to() {
+	let rules = TrailerRules {
+		if_missing: IfMissing::DoNothing,
+		..TrailerRules::default()
+	};
+
+	assert_eq!(rules.merge(&lines(&[REVIEWED]), lines(&[ALICE])), lines(&[REVIEWED]));
+	assert_eq!(rules.merge(&lines(&[ALICE]), lines(&[BOB])), lines(&[ALICE, BOB]));
+}
+
+#[test]
+fn recognize_trailers_with_any_configured_separator() {
+	let rules = TrailerRules {
+		separators: ":#".to_string(),
+		..TrailerRules::default()
+	};
+
+	assert!(rules.is_trailer("Bug #42"));
+	assert!(rules.is_trailer(SIGNED_OFF));
+	assert!(!TrailerRules::default().is_trailer("Bug #42"));
+	assert!(!rules.is_trailer("https://example.com"));
+	assert!(!rules.is_trailer("not a: trailer"));
+}
+
+#[test]
+fn recognize_trailers_with_a_multi_byte_separator() {
+	let rules = TrailerRules {
+		separators: ":→".to_string(),
+		..TrailerRules::default()
+	};
+
+	assert!(rules.is_trailer("Fixes → 42"));
+	assert!(rules.is_trailer("Fixes →"));
+	assert!(!rules.is_trailer("a→b"));
+}
+
+#[test]
+fn load_from_git_config() -> crate::Result<()> {
+	let path = format!("/tmp/coa/trailer_rules/{}", Uuid::new_v4());
+	std::fs::create_dir_all("/tmp/coa/trailer_rules")?;
+	let mut config = Config::open(std::path::Path::new(&path))?;
+	config.set_str("trailer.where", "start")?;
+	config.set_str("trailer.ifExists", "replace")?;
+	config.set_str("trailer.ifMissing", "doNothing")?;
+	config.set_str("trailer.separators", ":#")?;
+
+	let rules = TrailerRules::from(&config);
+
+	std::fs::remove_file(path).ok();
+	assert_eq!(
+		rules,
+		TrailerRules {
+			place: Where::Start,
+			if_exists: IfExists::Replace,
+			if_missing: IfMissing::DoNothing,
+			separators: ":#".to_string(),
+		}
+	);
+	Ok(())
+}
@@ -0,0 +1,187 @@
+use git2::Config;
+
+/// Where new trailers go, see `trailer.where` in git-interpret-trailers
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Where {
+	#[default]
+	End,
+	Start,
+	After,
+	Before,
+}
+
+/// What to do when a trailer with the same key is already there, see `trailer.ifExists`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum IfExists {
+	AddIfDifferentNeighbor,
+	// Unlike git, so selecting an author already in the message doesn't duplicate it
+	#[default]
+	AddIfDifferent,
+	Add,
+	Replace,
+	DoNothing,
+}
+
+/// What to do when no trailer with the same key is there, see `trailer.ifMissing`
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum IfMissing {
+	#[default]
+	Add,
+	DoNothing,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrailerRules {
+	pub place: Where,
+	pub if_exists: IfExists,
+	pub if_missing: IfMissing,
+	pub separators: String,
+}
+
+impl Default for TrailerRules {
+	fn default() -> Self {
+		Self {
+			place: Where::default(),
+			if_exists: IfExists::default(),
+			if_missing: IfMissing::default(),
+			separators: ":".to_string(),
+		}
+	}
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Anchor {
+	Start,
+	Before(usize),
+	After(usize),
+	End,
+}
+
+impl TrailerRules {
+	pub fn from(config: &Config) -> Self {
+		let get = |key: &str| config.get_string(key).ok().map(|value| value.to_lowercase());
+		let place = match get("trailer.where").as_deref() {
+			Some("start") => Where::Start,
+			Some("after") => Where::After,
+			Some("before") => Where::Before,
+			_ => Where::End,
+		};
+		let if_exists = match get("trailer.ifexists").as_deref() {
+			Some("addifdifferentneighbor") => IfExists::AddIfDifferentNeighbor,
+			Some("add") => IfExists::Add,
+			Some("replace") => IfExists::Replace,
+			Some("donothing") => IfExists::DoNothing,
+			_ => IfExists::AddIfDifferent,
+		};
+		let if_missing = match get("trailer.ifmissing").as_deref() {
+			Some("donothing") => IfMissing::DoNothing,
+			_ => IfMissing::Add,
+		};
+		let separators = config
+			.get_string("trailer.separators")
+			.ok()
+			.filter(|separators| !separators.is_empty())
+			.unwrap_or_else(|| ":".to_string());
+
+		Self {
+			place,
+			if_exists,
+			if_missing,
+			separators,
+		}
+	}
+
+	/// `Token<separator> value`, where the token is made of alphanumerics and dashes
+	pub fn is_trailer(&self, line: &str) -> bool {
+		self.key_of(line).is_some()
+	}
+
+	fn key_of<'a>(&self, line: &'a str) -> Option<&'a str> {
+		let (token, value) = line.split_at(line.find(|c| self.separators.contains(c))?);
+		let spaced = token.ends_with(char::is_whitespace);
+		let token = token.trim_end();
+		let valid_token = !token.is_empty() && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+		// Either side of the separator must be spaced, so `https://...` isn't taken for a trailer but `Bug #42` is
+		let value = value
+			.chars()
+			.next()
+			.map_or(value, |separator| &value[separator.len_utf8()..]);
+		let spaced = spaced || value.is_empty() || value.starts_with(char::is_whitespace);
+		(valid_token && spaced).then_some(token)
+	}
+
+	fn same_key(&self, line: &str, other: &str) -> bool {
+		match (self.key_of(line), self.key_of(other)) {
+			(Some(key), Some(other_key)) => key.eq_ignore_ascii_case(other_key),
+			_ => line == other,
+		}
+	}
+
+	/// Places the new trailers among the existing ones
+	pub fn merge(&self, existing: &[String], new: Vec<String>) -> Vec<String> {
+		let mut existing = existing.to_vec();
+		if self.if_exists == IfExists::Replace {
+			existing.retain(|trailer| !new.iter().any(|added| self.same_key(trailer, added)));
+		}
+
+		let mut placed: Vec<(Anchor, String)> = Vec::new();
+		for trailer in new {
+			let anchor = self.anchor_for(&existing, &trailer);
+			let neighbor = placed
+				.iter()
+				.rev()
+				.find(|(placed_anchor, _)| *placed_anchor == anchor)
+				.map(|(_, placed)| placed)
+				.or_else(|| Self::neighbor_of(&existing, anchor));
+			if self.should_add(&existing, &trailer, neighbor) {
+				placed.push((anchor, trailer));
+			}
+		}
+
+		let at = |anchor: Anchor| {
+			placed
+				.iter()
+				.filter(move |(placed_anchor, _)| *placed_anchor == anchor)
+				.map(|(_, trailer)| trailer.clone())
+		};
+		let mut merged: Vec<String> = at(Anchor::Start).collect();
+		for (i, trailer) in existing.iter().enumerate() {
+			merged.extend(at(Anchor::Before(i)));
+			merged.push(trailer.clone());
+			merged.extend(at(Anchor::After(i)));
+		}
+		merged.extend(at(Anchor::End));
+		merged
+	}
+
+	fn anchor_for(&self, existing: &[String], trailer: &str) -> Anchor {
+		let same_key = |line: &String| self.same_key(line, trailer);
+		match self.place {
+			Where::End => Anchor::End,
+			Where::Start => Anchor::Start,
+			Where::After => existing.iter().rposition(same_key).map_or(Anchor::End, Anchor::After),
+			Where::Before => existing.iter().position(same_key).map_or(Anchor::Start, Anchor::Before),
+		}
+	}
+
+	fn neighbor_of(existing: &[String], anchor: Anchor) -> Option<&String> {
+		match anchor {
+			Anchor::Start => existing.first(),
+			Anchor::Before(i) | Anchor::After(i) => existing.get(i),
+			Anchor::End => existing.last(),
+		}
+	}
+
+	fn should_add(&self, existing: &[String], trailer: &str, neighbor: Option<&String>) -> bool {
+		let key_exists = existing.iter().any(|line| self.same_key(line, trailer));
+		if !key_exists {
+			return self.if_missing == IfMissing::Add;
+		}
+		match self.if_exists {
+			IfExists::AddIfDifferentNeighbor => neighbor.map(String::as_str) != Some(trailer),
+			IfExists::AddIfDifferent => !existing.iter().any(|line| line == trailer),
+			IfExists::Add | IfExists::Replace => true,
+			IfExists::DoNothing => false,
+		}
+	}
+}
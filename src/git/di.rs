@@ -1,12 +1,16 @@
-use super::core::conf_provider::GitConfProvider;
+use super::comment::CommentString;
+use super::conventional::Conventional;
+use super::core::conf_provider::{ConfProvider, GitConfProvider};
 use super::core::editor::file_editor::FileEditor;
 use super::core::hook::Hook;
 use super::core::libgit::wrapper::LibGitWrapper;
 use super::core::service::GitService;
-use crate::common::env;
+use super::lint::LintRules;
+use super::ticket::{BranchTicket, TicketPattern};
 use crate::common::fs::file_reader::FileReader;
 use crate::common::fs::file_writer::FileWriter;
 use crate::common::runner::CommandRunner;
+use crate::common::{conf, env};
 use crate::Result;
 
 type Editor = FileEditor<CommandRunner, GitConfProvider>;
@@ -15,10 +19,28 @@ pub type Service = GitService<LibGitWrapper<FileReader>, GitHook, Editor, FileWr
 
 pub fn init() -> Result<Service> {
 	let cwd = env::cwd()?;
+	let git_wrapper = LibGitWrapper::from(&cwd, FileReader)?;
+	let trailer_rules = git_wrapper.trailer_rules();
+	let cleanup = git_wrapper.cleanup();
+	let verbose = git_wrapper.verbose();
+	let template = git_wrapper.template();
+	let branch = git_wrapper.branch();
+	let tickets = TicketPattern::configured()?;
+	let branch_ticket = BranchTicket::configured(&branch, &tickets);
+	let conventional = conf::conventional_commits().then(|| Conventional::configured().with_tickets(tickets.clone()));
+	let lint_rules = LintRules::configured(&branch, tickets);
 	Ok(GitService::new(
-		LibGitWrapper::from(&cwd, FileReader)?,
+		git_wrapper,
 		Hook::new(CommandRunner),
 		FileEditor::new(CommandRunner, GitConfProvider),
 		FileWriter,
-	))
+	)
+	.with_trailer_rules(trailer_rules)
+	.with_cleanup(cleanup)
+	.with_verbose(verbose)
+	.with_template(template)
+	.with_lint_rules(lint_rules)
+	.with_branch_ticket(branch_ticket)
+	.with_conventional(conventional)
+	.with_comment_string(CommentString::from(GitConfProvider.get_comment_string())))
 }
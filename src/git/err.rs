@@ -6,6 +6,9 @@ pub enum GitError {
 	Editor,
 	Hook(String),
 	LibGit(String),
+	Conventional(String),
+	Lint(String),
+	TicketPattern(String),
 }
 
 impl Error for GitError {
@@ -23,6 +26,9 @@ impl PartialEq for GitError {
 			(GitError::Editor, GitError::Editor)
 				| (GitError::Hook(_), GitError::Hook(_))
 				| (GitError::LibGit(_), GitError::LibGit(_))
+				| (GitError::Conventional(_), GitError::Conventional(_))
+				| (GitError::Lint(_), GitError::Lint(_))
+				| (GitError::TicketPattern(_), GitError::TicketPattern(_))
 		)
 	}
 }
@@ -34,6 +40,9 @@ impl Display for GitError {
 			GitError::Editor => write!(f, "Editor"),
 			GitError::Hook(hook) => write!(f, "{hook} hook"),
 			GitError::LibGit(err) => write!(f, "{err}"),
+			GitError::Conventional(err) => write!(f, "Conventional Commits: {err}"),
+			GitError::Lint(violations) => write!(f, "Commit message checks failed:\n{violations}"),
+			GitError::TicketPattern(pattern) => write!(f, "Invalid ticket pattern {pattern}"),
 		}
 	}
 }
@@ -53,5 +62,17 @@ mod tests {
 			format!("{}", GitError::LibGit("some error".to_string())),
 			"Git: some error"
 		);
+		assert_eq!(
+			format!("{}", GitError::Conventional("some error".to_string())),
+			"Git: Conventional Commits: some error"
+		);
+		assert_eq!(
+			format!("{}", GitError::Lint("- some violation".to_string())),
+			"Git: Commit message checks failed:\n- some violation"
+		);
+		assert_eq!(
+			format!("{}", GitError::TicketPattern("(".to_string())),
+			"Git: Invalid ticket pattern ("
+		);
 	}
 }
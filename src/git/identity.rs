@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct Identity {
+	name: String,
+	email: String,
+}
+
+impl Identity {
+	pub fn from(name: &str, email: &str) -> Self {
+		Self {
+			name: String::from(name),
+			email: String::from(email),
+		}
+	}
+
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	pub fn email(&self) -> &str {
+		&self.email
+	}
+}
@@ -0,0 +1,161 @@
+use super::conventional::Subject;
+use super::ticket::TicketPattern;
+use super::CommitMessage;
+use crate::common::conf;
+
+/// Checks run on the final commit message, each one off when zeroed
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LintRules {
+	pub subject_max_length: usize,
+	pub subject_no_period: bool,
+	pub imperative_mood: bool,
+	pub body_max_width: usize,
+	pub require_ticket: bool,
+	pub min_co_authors: usize,
+	/// The ticket of the branch, which references in the message have to agree with
+	pub branch_ticket: Option<String>,
+	pub tickets: TicketPattern,
+}
+
+impl LintRules {
+	/// The co-authors minimum only applies on the configured branches
+	pub fn configured(branch: &str, tickets: TicketPattern) -> Self {
+		let branches = conf::lint_min_co_authors_branches();
+		let on_branch = branches.is_empty() || branches.iter().any(|pattern| matches_branch(pattern, branch));
+		Self {
+			subject_max_length: conf::lint_subject_max_length(),
+			subject_no_period: conf::lint_subject_no_period(),
+			imperative_mood: conf::lint_imperative_mood(),
+			body_max_width: conf::lint_body_max_width(),
+			require_ticket: conf::lint_require_ticket(),
+			min_co_authors: if on_branch { conf::lint_min_co_authors() } else { 0 },
+			branch_ticket: if conf::lint_ticket_matches_branch() {
+				tickets.find_in_branch(branch)
+			} else {
+				None
+			},
+			tickets,
+		}
+	}
+
+	pub fn is_off(&self) -> bool {
+		self.subject_max_length == 0
+			&& !self.subject_no_period
+			&& !self.imperative_mood
+			&& self.body_max_width == 0
+			&& !self.require_ticket
+			&& self.min_co_authors == 0
+			&& self.branch_ticket.is_none()
+	}
+
+	pub fn check(&self, message: &CommitMessage) -> Vec<String> {
+		let mut violations = self.check_subject(message);
+		if self.body_max_width > 0 {
+			for line in message.body() {
+				let width = line.chars().count();
+				// Links can't be wrapped
+				if width > self.body_max_width && !line.contains("://") {
+					violations.push(format!(
+						"Body line is {width} characters wide, {} at most",
+						self.body_max_width
+					));
+				}
+			}
+		}
+		violations.extend(self.check_co_authors(message));
+		violations
+	}
+
+	/// The check selecting the co-authors again can fix
+	pub fn check_co_authors(&self, message: &CommitMessage) -> Option<String> {
+		let co_authors = message.authors().len();
+		(co_authors < self.min_co_authors).then(|| {
+			format!(
+				"{co_authors} co-author(s), {} at least on this branch",
+				self.min_co_authors
+			)
+		})
+	}
+
+	/// The checks rewording the subject can fix, ticket references usually going there
+	pub fn check_subject(&self, message: &CommitMessage) -> Vec<String> {
+		let subject = message.subject();
+		// The description of a Conventional Commit is what reads as a sentence
+		let description = Subject::parse(self.tickets.strip_leading(subject))
+			.map_or(subject.to_string(), |parsed| parsed.description().to_string());
+		let mut violations = Vec::new();
+
+		let length = subject.chars().count();
+		if self.subject_max_length > 0 && length > self.subject_max_length {
+			violations.push(format!(
+				"Subject is {length} characters long, {} at most",
+				self.subject_max_length
+			));
+		}
+		if self.subject_no_period && subject.ends_with('.') {
+			violations.push("Subject ends with a period".to_string());
+		}
+		if self.imperative_mood {
+			// A leading ticket reference, as in `ABC-123: Add login`, isn't part of the sentence
+			if let Some(word) = description
+				.split_whitespace()
+				.find(|word| self.tickets.find(word).is_none())
+				.filter(|word| !is_imperative(word))
+			{
+				violations.push(format!(
+					"Subject should start in the imperative mood, not with \"{word}\""
+				));
+			}
+		}
+		// Trailers naming someone are left out, as emails may look like references
+		let text = [message.subject()]
+			.into_iter()
+			.chain(message.body().iter().map(String::as_str))
+			.chain(
+				message
+					.trailers()
+					.iter()
+					.map(String::as_str)
+					.filter(|line| !line.contains('<')),
+			)
+			.collect::<Vec<_>>()
+			.join("\n");
+		let references = self.tickets.find_all(&text);
+		if self.require_ticket && references.is_empty() {
+			violations.push("No ticket reference (e.g. ABC-123)".to_string());
+		}
+		if let Some(ticket) = &self.branch_ticket {
+			if !references.is_empty() && !references.contains(ticket) {
+				violations.push(format!(
+					"References {} while the branch is about {ticket}",
+					references.join(", ")
+				));
+			}
+		}
+		violations
+	}
+}
+
+/// Imperative verbs the rough guess would take for past tense, gerunds or third person
+const IMPERATIVE_EXCEPTIONS: [&str; 16] = [
+	"bring", "ring", "spring", "string", "ping", "embed", "seed", "feed", "need", "speed", "shed", "bleed", "proceed",
+	"exceed", "succeed", "alias",
+];
+
+/// A rough guess: past tense, gerunds and third person verbs are not imperative
+fn is_imperative(word: &str) -> bool {
+	let word = word.to_lowercase();
+	if IMPERATIVE_EXCEPTIONS.contains(&word.as_str()) {
+		return true;
+	}
+	let third_person = word.ends_with('s') && !["ss", "us", "is"].iter().any(|end| word.ends_with(end));
+	!(word.ends_with("ed") || word.ends_with("ing") || third_person)
+}
+
+/// A trailing `*` matches any suffix
+fn matches_branch(pattern: &str, branch: &str) -> bool {
+	match pattern.strip_suffix('*') {
+		Some(prefix) => branch.starts_with(prefix),
+		None => pattern == branch,
+	}
+}
@@ -1,12 +1,22 @@
+pub mod cleanup;
+pub mod comment;
 pub mod commit_mode;
+pub mod conventional;
 pub mod di;
 pub mod err;
+pub mod identity;
+pub mod lint;
+pub mod preview;
+pub mod ticket;
+
+pub use self::core::commit_message::CommitMessage;
 
 mod core {
-	mod commit_message;
+	pub mod commit_message;
 	pub mod conf_provider;
 	pub mod hook;
 	pub mod service;
+	pub mod trailer_rules;
 
 	pub mod libgit {
 		mod status_builder;
@@ -32,11 +42,20 @@ mod core {
 	mod test {
 		mod commit_message {
 			mod helper;
+			mod should_clean_up;
 			mod should_create;
+			mod should_keep_layout;
 			mod should_parse;
+			mod should_parse_trailers;
 		}
 
+		mod cleanup_should;
+		mod comment_should;
+		mod conventional_should;
 		mod hook_should;
+		mod lint_should;
+		mod ticket_should;
+		mod trailer_rules_should;
 		mod service {
 			mod amend_with_editor_should;
 			mod amend_without_editor_should;
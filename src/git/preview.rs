@@ -0,0 +1,20 @@
+use super::cleanup::Cleanup;
+use super::identity::Identity;
+use super::CommitMessage;
+
+/// A commit ready to be created, its message written and checked
+pub struct Pending {
+	pub(super) amend: bool,
+	pub(super) driver: Option<Identity>,
+	pub(super) cleanup: Cleanup,
+	pub(super) comment: String,
+}
+
+/// What a pending commit is made of, to be confirmed before creating it
+pub struct Preview {
+	pub message: CommitMessage,
+	pub author: Identity,
+	pub committer: Identity,
+	pub staged: Vec<String>,
+	pub amend: bool,
+}
@@ -0,0 +1,130 @@
+use super::conventional::Subject;
+use super::err::GitError;
+use crate::common::conf;
+use crate::Result;
+use regex::Regex;
+
+const DEFAULT_PATTERN: &str = r"\b[A-Z][A-Z0-9]+-[0-9]+\b";
+
+/// Finds ticket references, the first capture group being the reference when there is one.
+/// References are upper-cased, the way issue trackers show them
+#[derive(Debug, Clone)]
+pub struct TicketPattern(Regex);
+
+impl TicketPattern {
+	pub fn new(pattern: &str) -> Result<Self> {
+		Regex::new(pattern)
+			.map(Self)
+			.map_err(|_| GitError::TicketPattern(pattern.to_string()).into())
+	}
+
+	pub fn configured() -> Result<Self> {
+		Self::new(&conf::ticket_pattern())
+	}
+
+	pub fn find(&self, text: &str) -> Option<String> {
+		self.find_all(text).into_iter().next()
+	}
+
+	/// Branch names being mostly lower case, they are also searched upper-cased
+	pub fn find_in_branch(&self, branch: &str) -> Option<String> {
+		self.find(branch).or_else(|| self.find(&branch.to_uppercase()))
+	}
+
+	/// The subject without its leading reference, as in `ABC-123: Add login` or `[ABC-123] Add login`
+	pub fn strip_leading<'a>(&self, subject: &'a str) -> &'a str {
+		match subject.split_once(char::is_whitespace) {
+			Some((word, rest)) if self.find(word).is_some() => rest.trim_start(),
+			_ => subject,
+		}
+	}
+
+	pub fn find_all(&self, text: &str) -> Vec<String> {
+		self.0
+			.captures_iter(text)
+			.filter_map(|captures| captures.get(1).or_else(|| captures.get(0)))
+			.map(|reference| reference.as_str().to_uppercase())
+			.collect()
+	}
+}
+
+impl Default for TicketPattern {
+	fn default() -> Self {
+		Self(Regex::new(DEFAULT_PATTERN).expect("the default ticket pattern to be valid"))
+	}
+}
+
+impl PartialEq for TicketPattern {
+	fn eq(&self, other: &Self) -> bool {
+		self.0.as_str() == other.0.as_str()
+	}
+}
+
+/// Where the ticket of the branch goes in the message
+#[derive(Debug, Clone, PartialEq)]
+pub enum Placement {
+	/// `{ticket}` being replaced by the reference
+	Prefix(String),
+	/// The trailer key
+	Trailer(String),
+}
+
+/// The ticket the current branch is about, to be referenced in the message
+#[derive(Debug, Clone, PartialEq)]
+pub struct BranchTicket {
+	reference: String,
+	placement: Placement,
+	tickets: TicketPattern,
+}
+
+impl BranchTicket {
+	pub fn new(reference: &str, placement: Placement) -> Self {
+		Self {
+			reference: reference.to_string(),
+			placement,
+			tickets: TicketPattern::default(),
+		}
+	}
+
+	/// What counts as a reference in the message
+	pub fn with_tickets(mut self, tickets: TicketPattern) -> Self {
+		self.tickets = tickets;
+		self
+	}
+
+	/// Only when configured and the branch does refer to a ticket
+	pub fn configured(branch: &str, tickets: &TicketPattern) -> Option<Self> {
+		let placement = match conf::ticket_from_branch().to_lowercase().as_str() {
+			"prefix" => Placement::Prefix(conf::ticket_prefix()),
+			"trailer" => Placement::Trailer(conf::ticket_trailer()),
+			_ => return None,
+		};
+		let reference = tickets.find_in_branch(branch)?;
+		Some(Self::new(&reference, placement).with_tickets(tickets.clone()))
+	}
+
+	/// Left as is when the message already refers to the ticket.
+	/// The prefix of a Conventional Commit goes before the description, the type staying first
+	pub fn reference_in(&self, message: &str, trailers: &mut Vec<String>) -> String {
+		if self.tickets.find_all(message).contains(&self.reference) {
+			return message.to_string();
+		}
+		match &self.placement {
+			Placement::Prefix(prefix) => {
+				let prefix = prefix.replace("{ticket}", &self.reference);
+				let subject = message.lines().next().unwrap_or_default();
+				match subject.split_once(": ").filter(|_| Subject::parse(subject).is_some()) {
+					Some((head, _)) => {
+						let (head, description) = message.split_at(head.len() + ": ".len());
+						format!("{head}{prefix}{description}")
+					}
+					None => format!("{prefix}{message}"),
+				}
+			}
+			Placement::Trailer(key) => {
+				trailers.push(format!("{key}: {}", self.reference));
+				message.to_string()
+			}
+		}
+	}
+}
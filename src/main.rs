@@ -1,22 +1,109 @@
-use args::Args;
+use args::{Args, Command, MobAction, PairsAction};
+use authors::author::AuthorsProvider;
+use authors::template::SignatureTemplate;
 use clap::Parser;
+use common::conf;
+use common::fs::file_reader::FileReader;
 use error::Error;
+use git::ticket::TicketPattern;
+use mob::di::Mob;
 use orchestrator::Orchestrator;
+use std::io::{IsTerminal, Read};
+use templates::library::Templates;
 
 fn main() {
-	let args = Args::parse();
-	if let Err(e) = run(args) {
+	if let Err(e) = read_inputs(Args::parse()).and_then(run) {
 		eprintln!("[Error] {e}");
 		std::process::exit(1);
 	}
 }
 
+fn read_inputs(args: Args) -> Result<Args> {
+	let stdin = std::io::stdin();
+	let mut stdin = (!stdin.is_terminal()).then(|| stdin.lock());
+	args.with_inputs(&FileReader, stdin.as_mut().map(|stdin| stdin as &mut dyn Read))
+}
+
 pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
 fn run(args: Args) -> Result<()> {
+	let signature_template = SignatureTemplate::configured()?;
+	TicketPattern::configured()?;
 	let cli = ui::di::init()?;
-	let service = git::di::init()?;
-	let provider = authors::di::init(&args.file)?;
-	Orchestrator::exec(args, cli, service, provider)
+	match &args.command {
+		Some(Command::Mob { remote, action }) => {
+			let mob = mob::di::init(remote.as_ref(), None)?;
+			match action {
+				MobAction::Start => {
+					let provider = authors_provider(&args, &mob)?;
+					Orchestrator::start_mob(args, cli, provider, mob, signature_template, rota::di::init())
+				}
+				MobAction::Join => Orchestrator::join_mob(&mob),
+				MobAction::Stop => Orchestrator::stop_mob(&mob),
+				MobAction::Timer { minutes, bell, notify } => {
+					let notify_cmd = notify.clone().unwrap_or_else(conf::timer_notify_cmd);
+					Orchestrator::mob_timer(&cli, &mob, *minutes, *bell, &notify_cmd)
+				}
+			}
+		}
+		Some(Command::Handoff(wip)) => {
+			let mob = mob::di::init(wip.remote.as_ref(), wip.wip_branch.as_ref())?;
+			let provider = authors_provider(&args, &mob)?;
+			Orchestrator::handoff(args, cli, provider, mob, signature_template, git::di::init)
+		}
+		Some(Command::Take(wip)) => Orchestrator::take(&mob::di::init(wip.remote.as_ref(), wip.wip_branch.as_ref())?),
+		Some(Command::Done(wip)) => {
+			let mob = mob::di::init(wip.remote.as_ref(), wip.wip_branch.as_ref())?;
+			let provider = authors_provider(&args, &mob)?;
+			let templates = templates(&args)?;
+			Orchestrator::done(args, cli, provider, mob, signature_template, templates, git::di::init)
+		}
+		Some(Command::Pairs {
+			action: PairsAction::Suggest { days, json },
+		}) => {
+			let mob = mob::di::init(None, None)?;
+			let provider = authors_provider(&args, &mob)?;
+			let (days, json) = (*days, *json);
+			Orchestrator::suggest_pairs(
+				args,
+				cli,
+				provider,
+				mob,
+				signature_template,
+				&pairs::di::init()?,
+				days,
+				json,
+			)
+		}
+		None => {
+			let service = git::di::init()?;
+			let mob = mob::di::init(None, None)?;
+			let provider = authors_provider(&args, &mob)?;
+			let templates = templates(&args)?;
+			Orchestrator::exec(
+				args,
+				cli,
+				service,
+				provider,
+				mob,
+				signature_template,
+				rota::di::init(),
+				templates,
+			)
+		}
+	}
+}
+
+// Fall back to the members of the current mob session when there is no authors file
+fn authors_provider(args: &Args, mob: &Mob) -> Result<Box<dyn AuthorsProvider>> {
+	authors::di::init(args.file.as_ref()).or_else(|e| match mob.current() {
+		Some(session) => Ok(Box::new(session) as Box<dyn AuthorsProvider>),
+		None => Err(e),
+	})
+}
+
+// Only looked up when asked for, so a missing library doesn't get in the way otherwise
+fn templates(args: &Args) -> Result<Option<Templates>> {
+	args.template.as_ref().map(|_| templates::di::init()).transpose()
 }
 
 mod args;
@@ -24,5 +111,12 @@ mod authors;
 mod common;
 mod error;
 mod git;
+mod mob;
 mod orchestrator;
+mod pairs;
+mod rota;
+mod templates;
 mod ui;
+
+#[cfg(test)]
+mod args_should;
@@ -0,0 +1,16 @@
+use super::libgit::session_store::LibGitSessionStore;
+use super::libgit::wip_branch::LibGitWipBranch;
+use super::service::MobService;
+use crate::common::{conf, env};
+use crate::Result;
+
+pub type Mob = MobService<LibGitSessionStore, LibGitWipBranch>;
+
+pub fn init(remote: Option<&String>, wip_branch: Option<&String>) -> Result<Mob> {
+	let cwd = env::cwd()?;
+	let remote = remote.cloned().unwrap_or_else(conf::mob_remote);
+	Ok(MobService::new(
+		LibGitSessionStore::from(&cwd, &remote)?,
+		LibGitWipBranch::from(&cwd, &remote, &conf::wip_branch_prefix(), wip_branch)?,
+	))
+}
@@ -0,0 +1,57 @@
+use crate::error::Error;
+use std::{any::Any, fmt::Display};
+
+#[derive(Debug)]
+pub enum MobError {
+	NoSession,
+	Remote(String),
+	Branch(String),
+}
+
+impl Error for MobError {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl std::error::Error for MobError {}
+
+impl PartialEq for MobError {
+	fn eq(&self, other: &Self) -> bool {
+		matches!(
+			(self, other),
+			(MobError::NoSession, MobError::NoSession)
+				| (MobError::Remote(_), MobError::Remote(_))
+				| (MobError::Branch(_), MobError::Branch(_))
+		)
+	}
+}
+
+impl Display for MobError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Mob: ")?;
+		match self {
+			MobError::NoSession => write!(f, "No session found"),
+			MobError::Remote(err) => write!(f, "Remote failed -> {err}"),
+			MobError::Branch(err) => write!(f, "Wip branch -> {err}"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_mob_error_display() {
+		assert_eq!(format!("{}", MobError::NoSession), "Mob: No session found");
+		assert_eq!(
+			format!("{}", MobError::Remote("rejected".to_string())),
+			"Mob: Remote failed -> rejected"
+		);
+		assert_eq!(
+			format!("{}", MobError::Branch("diverged".to_string())),
+			"Mob: Wip branch -> diverged"
+		);
+	}
+}
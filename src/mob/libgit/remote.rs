@@ -0,0 +1,64 @@
+use crate::mob::err::MobError;
+use crate::Result;
+use git2::{Cred, FetchOptions, FetchPrune, PushOptions, RemoteCallbacks, Repository};
+
+// libgit2 keeps asking for credentials as long as the callback returns some
+const MAX_CREDENTIAL_ATTEMPTS: usize = 3;
+
+pub fn fetch(repo: &Repository, remote: &str, refspecs: &[String]) -> Result<()> {
+	let config = repo.config()?;
+	let mut options = FetchOptions::new();
+	options.remote_callbacks(callbacks(&config)).prune(FetchPrune::On);
+
+	repo.find_remote(remote)
+		.map_err(|_| MobError::Remote(format!("No remote named {remote}")))?
+		.fetch(refspecs, Some(&mut options), None)
+		.map_err(|e| MobError::Remote(e.message().to_string()))?;
+	Ok(())
+}
+
+pub fn push(repo: &Repository, remote: &str, refspecs: &[String]) -> Result<()> {
+	let config = repo.config()?;
+	let mut rejections = Vec::new();
+	{
+		let mut callbacks = callbacks(&config);
+		callbacks.push_update_reference(|reference, status| {
+			if let Some(reason) = status {
+				rejections.push(format!("{reference} ({reason})"));
+			}
+			Ok(())
+		});
+		let mut options = PushOptions::new();
+		options.remote_callbacks(callbacks);
+
+		repo.find_remote(remote)
+			.map_err(|_| MobError::Remote(format!("No remote named {remote}")))?
+			.push(refspecs, Some(&mut options))
+			.map_err(|e| MobError::Remote(e.message().to_string()))?;
+	}
+
+	if rejections.is_empty() {
+		Ok(())
+	} else {
+		Err(MobError::Remote(format!("Rejected {}", rejections.join(", "))).into())
+	}
+}
+
+fn callbacks(config: &git2::Config) -> RemoteCallbacks<'_> {
+	let mut attempts = 0;
+	let mut callbacks = RemoteCallbacks::new();
+	callbacks.credentials(move |url, username, allowed| {
+		attempts += 1;
+		if attempts > MAX_CREDENTIAL_ATTEMPTS {
+			return Err(git2::Error::from_str("Could not authenticate"));
+		}
+		if allowed.is_ssh_key() {
+			Cred::ssh_key_from_agent(username.unwrap_or("git"))
+		} else if allowed.is_user_pass_plaintext() {
+			Cred::credential_helper(config, url, username)
+		} else {
+			Cred::default()
+		}
+	});
+	callbacks
+}
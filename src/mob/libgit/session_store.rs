@@ -0,0 +1,111 @@
+use super::remote;
+use crate::common::conf;
+use crate::git::err::GitError;
+use crate::mob::session::{Session, SessionStore};
+use crate::Result;
+use git2::{Commit, Repository, Signature, Tree};
+use std::path::PathBuf;
+
+const AUTHORS_FILE: &str = "authors.csv";
+const ROTATIONS_FILE: &str = "rotations.csv";
+const SESSION_COMMIT_MSG: &str = "co-author session";
+const BLOB_FILE_MODE: i32 = 0o100_644;
+
+pub struct LibGitSessionStore {
+	repo: Repository,
+	remote: String,
+	reference: String,
+}
+
+impl SessionStore for LibGitSessionStore {
+	fn load(&self) -> Result<Option<Session>> {
+		let Some(commit) = self.session_commit() else {
+			return Ok(None);
+		};
+		let tree = commit.tree()?;
+		let Some(authors) = self.read_file(&tree, AUTHORS_FILE) else {
+			return Ok(None);
+		};
+		let rotations = self.read_file(&tree, ROTATIONS_FILE).unwrap_or_default();
+		Ok(Some(Session::from(&authors, &rotations)))
+	}
+
+	fn save(&self, session: &Session) -> Result<()> {
+		let mut tree_builder = self.repo.treebuilder(None)?;
+		tree_builder.insert(
+			AUTHORS_FILE,
+			self.repo.blob(session.formatted_authors().as_bytes())?,
+			BLOB_FILE_MODE,
+		)?;
+		tree_builder.insert(
+			ROTATIONS_FILE,
+			self.repo.blob(session.formatted_rotations().as_bytes())?,
+			BLOB_FILE_MODE,
+		)?;
+		let tree = self.repo.find_tree(tree_builder.write()?)?;
+
+		// Keep previous states of the session as history
+		let parent = self.session_commit();
+		let signature = self.signature()?;
+		self.repo.commit(
+			Some(&self.reference),
+			&signature,
+			&signature,
+			SESSION_COMMIT_MSG,
+			&tree,
+			&parent.iter().collect::<Vec<_>>(),
+		)?;
+		Ok(())
+	}
+
+	fn delete(&self) -> Result<()> {
+		if let Ok(mut reference) = self.repo.find_reference(&self.reference) {
+			reference.delete()?;
+		}
+		Ok(())
+	}
+
+	fn push(&self) -> Result<()> {
+		// An empty source deletes the ref on the remote
+		let refspec = if self.session_commit().is_some() {
+			format!("+{0}:{0}", self.reference)
+		} else {
+			format!(":{}", self.reference)
+		};
+		remote::push(&self.repo, &self.remote, &[refspec])
+	}
+
+	fn fetch(&self) -> Result<()> {
+		remote::fetch(&self.repo, &self.remote, &[format!("+{0}:{0}", self.reference)])
+	}
+}
+
+impl LibGitSessionStore {
+	pub fn from(path: &PathBuf, remote: &str) -> Result<Self> {
+		let repo = Repository::discover(path).map_err(|_| GitError::LibGit("Could not open git repo".to_string()))?;
+		Ok(Self {
+			repo,
+			remote: remote.to_string(),
+			reference: conf::session_ref(),
+		})
+	}
+
+	fn session_commit(&self) -> Option<Commit<'_>> {
+		self.repo
+			.find_reference(&self.reference)
+			.and_then(|reference| reference.peel_to_commit())
+			.ok()
+	}
+
+	fn read_file(&self, tree: &Tree, name: &str) -> Option<String> {
+		let blob = self.repo.find_blob(tree.get_name(name)?.id()).ok()?;
+		Some(String::from_utf8_lossy(blob.content()).to_string())
+	}
+
+	fn signature(&self) -> Result<Signature<'_>> {
+		Ok(self
+			.repo
+			.signature()
+			.map_err(|_| GitError::LibGit("User name and/or email not set".to_string()))?)
+	}
+}
@@ -0,0 +1,77 @@
+use crate::Result;
+use git2::{Index, Repository, RepositoryInitOptions};
+use std::fs;
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+
+pub const TEST_DIR_PATH: &str = "/tmp/coa/mob";
+pub const REMOTE: &str = "origin";
+
+pub fn init_repo(path: &PathBuf) -> Result<Repository> {
+	let repo = Repository::init_opts(path, &RepositoryInitOptions::new())?;
+	let mut conf = repo.config()?;
+	conf.set_str("user.name", "a_name")?;
+	conf.set_str("user.email", "an_email")?;
+	drop(conf);
+
+	let tree = repo.find_tree(repo.index()?.write_tree()?)?;
+	repo.commit(
+		Some("HEAD"),
+		&repo.signature()?,
+		&repo.signature()?,
+		"initial commit",
+		&tree,
+		&[],
+	)?;
+	drop(tree);
+	Ok(repo)
+}
+
+pub fn init_bare_remote_for(repos: &[&Repository]) -> Result<PathBuf> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	Repository::init_bare(&path)?;
+	for repo in repos {
+		repo.remote(REMOTE, &path.to_string_lossy())?;
+	}
+	Ok(path)
+}
+
+pub fn clone_repo(remote_path: &Path, path: &PathBuf) -> Result<Repository> {
+	let repo = Repository::clone(&remote_path.to_string_lossy(), path)?;
+	let mut conf = repo.config()?;
+	conf.set_str("user.name", "another_name")?;
+	conf.set_str("user.email", "another_email")?;
+	drop(conf);
+	Ok(repo)
+}
+
+pub fn write_file(repo: &Repository, file_name: &str, content: &str) -> Result<()> {
+	let root = repo.path().parent().ok_or("Could not write file to test git tree")?;
+	fs::write(root.join(file_name), content)?;
+	Ok(())
+}
+
+pub fn commit_index(repo: &Repository, msg: &str) -> Result<()> {
+	let tree = repo.find_tree(fresh_index(repo)?.write_tree()?)?;
+	let head_commit = repo.head()?.peel_to_commit()?;
+	repo.commit(
+		Some("HEAD"),
+		&repo.signature()?,
+		&repo.signature()?,
+		msg,
+		&tree,
+		&[&head_commit],
+	)?;
+	Ok(())
+}
+
+pub fn fresh_index(repo: &Repository) -> Result<Index> {
+	let mut index = repo.index()?;
+	index.read(true)?;
+	Ok(index)
+}
+
+pub fn random_tmp_path_in(path: &str) -> PathBuf {
+	let random = Uuid::new_v4();
+	PathBuf::from(format!("{path}/{random}"))
+}
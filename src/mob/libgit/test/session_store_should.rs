@@ -0,0 +1,170 @@
+use crate::authors::author::Author;
+use crate::common::conf;
+use crate::error::assert_error_type;
+use crate::mob::err::MobError;
+use crate::mob::libgit::session_store::LibGitSessionStore;
+use crate::mob::libgit::test::helper::{init_bare_remote_for, init_repo, random_tmp_path_in, REMOTE, TEST_DIR_PATH};
+use crate::mob::session::{Session, SessionStore};
+use crate::Result;
+use git2::Repository;
+use std::fs;
+
+#[test]
+fn load_nothing_when_there_is_no_session() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	init_repo(&path)?;
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	let result = store.load();
+
+	fs::remove_dir_all(path).ok();
+	assert!(matches!(result, Ok(None)));
+	Ok(())
+}
+
+#[test]
+fn load_a_saved_session() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	init_repo(&path)?;
+	let session = a_session();
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	store.save(&session)?;
+	let result = store.load();
+
+	fs::remove_dir_all(path).ok();
+	assert!(matches!(result, Ok(Some(loaded)) if loaded == session));
+	Ok(())
+}
+
+#[test]
+fn keep_previous_sessions_as_history() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	store.save(&a_session())?;
+	store.save(&Session::new(vec![]))?;
+	let parents = repo
+		.find_reference(&conf::session_ref())?
+		.peel_to_commit()?
+		.parent_count();
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(parents, 1);
+	Ok(())
+}
+
+#[test]
+fn not_touch_branches_or_index() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let head_before = repo.head()?.target();
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	store.save(&a_session())?;
+	let head_after = repo.head()?.target();
+	let index_entries = repo.index()?.len();
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(head_before, head_after);
+	assert_eq!(index_entries, 0);
+	Ok(())
+}
+
+#[test]
+fn delete_a_session() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	init_repo(&path)?;
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	store.save(&a_session())?;
+	store.delete()?;
+	let result = store.load();
+
+	fs::remove_dir_all(path).ok();
+	assert!(matches!(result, Ok(None)));
+	Ok(())
+}
+
+#[test]
+fn share_a_session_through_the_remote() -> Result<()> {
+	let driver_path = random_tmp_path_in(TEST_DIR_PATH);
+	let next_driver_path = random_tmp_path_in(TEST_DIR_PATH);
+	let driver_repo = init_repo(&driver_path)?;
+	let next_driver_repo = init_repo(&next_driver_path)?;
+	let remote_path = init_bare_remote_for(&[&driver_repo, &next_driver_repo])?;
+	let session = a_session();
+
+	let driver_store = LibGitSessionStore::from(&driver_path, REMOTE)?;
+	driver_store.save(&session)?;
+	driver_store.push()?;
+	let next_driver_store = LibGitSessionStore::from(&next_driver_path, REMOTE)?;
+	next_driver_store.fetch()?;
+	let result = next_driver_store.load();
+
+	for path in [driver_path, next_driver_path, remote_path] {
+		fs::remove_dir_all(path).ok();
+	}
+	assert!(matches!(result, Ok(Some(fetched)) if fetched == session));
+	Ok(())
+}
+
+#[test]
+fn delete_a_session_from_the_remote() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let remote_path = init_bare_remote_for(&[&repo])?;
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	store.save(&a_session())?;
+	store.push()?;
+	store.delete()?;
+	store.push()?;
+	let remote_session = Repository::open_bare(&remote_path)?
+		.find_reference(&conf::session_ref())
+		.is_ok();
+
+	fs::remove_dir_all(path).ok();
+	fs::remove_dir_all(remote_path).ok();
+	assert!(!remote_session);
+	Ok(())
+}
+
+#[test]
+fn drop_a_session_that_is_no_longer_on_the_remote() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let remote_path = init_bare_remote_for(&[&repo])?;
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	store.save(&a_session())?;
+	store.fetch()?;
+	let result = store.load();
+
+	fs::remove_dir_all(path).ok();
+	fs::remove_dir_all(remote_path).ok();
+	assert!(matches!(result, Ok(None)));
+	Ok(())
+}
+
+#[test]
+fn fail_to_push_without_remote() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	init_repo(&path)?;
+
+	let store = LibGitSessionStore::from(&path, REMOTE)?;
+	store.save(&a_session())?;
+	let result = store.push();
+
+	fs::remove_dir_all(path).ok();
+	assert_error_type(&result, &MobError::Remote(String::new()));
+	Ok(())
+}
+
+fn a_session() -> Session {
+	Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	])
+}
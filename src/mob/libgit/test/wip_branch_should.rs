@@ -0,0 +1,274 @@
+use crate::error::assert_error_type;
+use crate::mob::err::MobError;
+use crate::mob::libgit::test::helper::{
+	clone_repo, commit_index, fresh_index, init_bare_remote_for, init_repo, random_tmp_path_in, write_file, REMOTE,
+	TEST_DIR_PATH,
+};
+use crate::mob::libgit::wip_branch::LibGitWipBranch;
+use crate::mob::wip::WipBranch;
+use crate::Result;
+use git2::{BranchType, Repository};
+use std::fs;
+use std::path::PathBuf;
+
+const PREFIX: &str = "mob/";
+
+#[test]
+fn stage_new_modified_and_deleted_files() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	write_file(&repo, "to_modify", "")?;
+	write_file(&repo, "to_delete", "")?;
+	let wip = wip_branch_for(&path)?;
+	wip.stage_all()?;
+	commit_index(&repo, "irrelevant")?;
+	write_file(&repo, "to_modify", "modified")?;
+	fs::remove_file(path.join("to_delete"))?;
+	write_file(&repo, "new", "")?;
+
+	let has_changes = wip.stage_all()?;
+	let head_tree = repo.head()?.peel_to_tree()?;
+	let staged = repo
+		.diff_tree_to_index(Some(&head_tree), Some(&fresh_index(&repo)?), None)?
+		.deltas()
+		.count();
+
+	fs::remove_dir_all(path).ok();
+	assert!(has_changes);
+	assert_eq!(staged, 3);
+	Ok(())
+}
+
+#[test]
+fn report_nothing_to_stage() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	init_repo(&path)?;
+
+	let has_changes = wip_branch_for(&path)?.stage_all()?;
+
+	fs::remove_dir_all(path).ok();
+	assert!(!has_changes);
+	Ok(())
+}
+
+#[test]
+fn switch_to_a_prefixed_wip_branch() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+
+	wip_branch_for(&path)?.switch_to_wip()?;
+	let current = repo.head()?.shorthand().map(ToString::to_string);
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(current, Some(format!("{PREFIX}master")));
+	Ok(())
+}
+
+#[test]
+fn not_switch_to_a_stale_wip_branch() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let initial_commit = repo.head()?.peel_to_commit()?;
+	repo.branch(&format!("{PREFIX}master"), &initial_commit, false)?;
+	commit_index(&repo, "irrelevant")?;
+
+	let result = wip_branch_for(&path)?.switch_to_wip();
+
+	fs::remove_dir_all(path).ok();
+	assert_error_type(&result, &MobError::Branch(String::new()));
+	Ok(())
+}
+
+#[test]
+fn hand_over_the_work_in_progress_through_the_remote() -> Result<()> {
+	let (driver_path, next_driver_path, remote_path) = setup_mob()?;
+	let driver_repo = Repository::open(&driver_path)?;
+	write_file(&driver_repo, "foo", "wip")?;
+
+	let driver_wip = wip_branch_for(&driver_path)?;
+	driver_wip.switch_to_wip()?;
+	driver_wip.stage_all()?;
+	commit_index(&driver_repo, "wip")?;
+	driver_wip.push()?;
+	wip_branch_for(&next_driver_path)?.take()?;
+	let next_driver_repo = Repository::open(&next_driver_path)?;
+	let current = next_driver_repo.head()?.shorthand().map(ToString::to_string);
+	let taken = fs::read_to_string(next_driver_path.join("foo"))?;
+
+	cleanup(&[driver_path, next_driver_path, remote_path]);
+	assert_eq!(current, Some(format!("{PREFIX}master")));
+	assert_eq!(taken, "wip");
+	Ok(())
+}
+
+#[test]
+fn report_missing_work_in_progress_when_taking() -> Result<()> {
+	let (driver_path, next_driver_path, remote_path) = setup_mob()?;
+
+	let result = wip_branch_for(&next_driver_path)?.take();
+
+	cleanup(&[driver_path, next_driver_path, remote_path]);
+	assert_error_type(&result, &MobError::Branch(String::new()));
+	Ok(())
+}
+
+#[test]
+fn squash_the_work_in_progress_into_staged_changes_on_base() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let base_commit = repo.head()?.peel_to_commit()?.id();
+	let wip = wip_branch_for(&path)?;
+	wip.switch_to_wip()?;
+	write_file(&repo, "foo", "")?;
+	wip.stage_all()?;
+	commit_index(&repo, "first")?;
+	write_file(&repo, "bar", "")?;
+	wip.stage_all()?;
+	commit_index(&repo, "second")?;
+
+	let messages = wip.squash_into_base()?;
+	let current = repo.head()?.shorthand().map(ToString::to_string);
+	let head_commit = repo.head()?.peel_to_commit()?;
+	let staged = repo
+		.diff_tree_to_index(Some(&head_commit.tree()?), Some(&fresh_index(&repo)?), None)?
+		.deltas()
+		.count();
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(messages, ["first", "second"]);
+	assert_eq!(current, Some("master".to_string()));
+	assert_eq!(head_commit.id(), base_commit);
+	assert_eq!(staged, 2);
+	Ok(())
+}
+
+#[test]
+fn return_to_the_wip_branch_keeping_the_squashed_changes_staged() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let wip = wip_branch_for(&path)?;
+	wip.switch_to_wip()?;
+	write_file(&repo, "foo", "")?;
+	wip.stage_all()?;
+	commit_index(&repo, "first")?;
+	wip.squash_into_base()?;
+
+	wip.return_to_wip()?;
+	let current = repo.head()?.shorthand().map(ToString::to_string);
+	let head_commit = repo.head()?.peel_to_commit()?;
+	let staged = repo
+		.diff_tree_to_index(Some(&head_commit.tree()?), Some(&fresh_index(&repo)?), None)?
+		.deltas()
+		.count();
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(current, Some(format!("{PREFIX}master")));
+	assert_eq!(staged, 0);
+	Ok(())
+}
+
+#[test]
+fn not_squash_when_base_has_diverged() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let wip = wip_branch_for(&path)?;
+	commit_index(&repo, "base moves on")?;
+	let base_tip = repo.head()?.peel_to_commit()?;
+	let initial_commit = base_tip.parent(0)?;
+	repo.branch(&format!("{PREFIX}master"), &initial_commit, false)?;
+	repo.set_head(&format!("refs/heads/{PREFIX}master"))?;
+
+	let result = wip.squash_into_base();
+
+	fs::remove_dir_all(path).ok();
+	assert_error_type(&result, &MobError::Branch(String::new()));
+	Ok(())
+}
+
+#[test]
+fn delete_the_wip_branch_locally_and_on_the_remote() -> Result<()> {
+	let (driver_path, next_driver_path, remote_path) = setup_mob()?;
+	let driver_wip = wip_branch_for(&driver_path)?;
+	driver_wip.switch_to_wip()?;
+	driver_wip.push()?;
+	let driver_repo = Repository::open(&driver_path)?;
+	driver_repo.set_head("refs/heads/master")?;
+
+	driver_wip.delete()?;
+	let local_exists = driver_repo
+		.find_branch(&format!("{PREFIX}master"), BranchType::Local)
+		.is_ok();
+	let remote_exists = Repository::open_bare(&remote_path)?
+		.find_reference(&format!("refs/heads/{PREFIX}master"))
+		.is_ok();
+
+	cleanup(&[driver_path, next_driver_path, remote_path]);
+	assert!(!local_exists);
+	assert!(!remote_exists);
+	Ok(())
+}
+
+#[test]
+fn squash_the_given_wip_branch_into_the_base_it_was_started_from() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = init_repo(&path)?;
+	let wip = LibGitWipBranch::from(&path, REMOTE, PREFIX, Some(&"shared".to_string()))?;
+	wip.switch_to_wip()?;
+	write_file(&repo, "foo", "")?;
+	wip.stage_all()?;
+	commit_index(&repo, "first")?;
+	// A later handoff, already on the wip branch
+	wip.switch_to_wip()?;
+
+	let messages = wip.squash_into_base()?;
+	let current = repo.head()?.shorthand().map(ToString::to_string);
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(messages, ["first"]);
+	assert_eq!(current, Some("master".to_string()));
+	Ok(())
+}
+
+#[test]
+fn take_the_given_wip_branch_keeping_track_of_its_base() -> Result<()> {
+	let (driver_path, next_driver_path, remote_path) = setup_mob()?;
+	let shared = Some("shared".to_string());
+	let driver_wip = LibGitWipBranch::from(&driver_path, REMOTE, PREFIX, shared.as_ref())?;
+	driver_wip.switch_to_wip()?;
+	driver_wip.push()?;
+
+	let next_driver_wip = LibGitWipBranch::from(&next_driver_path, REMOTE, PREFIX, shared.as_ref())?;
+	next_driver_wip.take()?;
+	let messages = next_driver_wip.squash_into_base()?;
+	let current = Repository::open(&next_driver_path)?
+		.head()?
+		.shorthand()
+		.map(ToString::to_string);
+
+	cleanup(&[driver_path, next_driver_path, remote_path]);
+	assert!(messages.is_empty());
+	assert_eq!(current, Some("master".to_string()));
+	Ok(())
+}
+
+fn wip_branch_for(path: &PathBuf) -> Result<LibGitWipBranch> {
+	LibGitWipBranch::from(path, REMOTE, PREFIX, None)
+}
+
+fn setup_mob() -> Result<(PathBuf, PathBuf, PathBuf)> {
+	let driver_path = random_tmp_path_in(TEST_DIR_PATH);
+	let next_driver_path = random_tmp_path_in(TEST_DIR_PATH);
+	let driver_repo = init_repo(&driver_path)?;
+	let remote_path = init_bare_remote_for(&[&driver_repo])?;
+	driver_repo
+		.find_remote(REMOTE)?
+		.push(&["refs/heads/master:refs/heads/master"], None)?;
+	clone_repo(&remote_path, &next_driver_path)?;
+	Ok((driver_path, next_driver_path, remote_path))
+}
+
+fn cleanup(paths: &[PathBuf]) {
+	for path in paths {
+		fs::remove_dir_all(path).ok();
+	}
+}
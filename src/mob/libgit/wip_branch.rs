@@ -0,0 +1,169 @@
+use super::remote;
+use crate::git::err::GitError;
+use crate::mob::err::MobError;
+use crate::mob::wip::{WipBranch, WipNames};
+use crate::Result;
+use git2::{build::CheckoutBuilder, BranchType, IndexAddOption, Repository, Sort};
+use std::path::PathBuf;
+
+pub struct LibGitWipBranch {
+	repo: Repository,
+	remote: String,
+	prefix: String,
+	wip_override: Option<String>,
+}
+
+impl WipBranch for LibGitWipBranch {
+	fn stage_all(&self) -> Result<bool> {
+		let mut index = self.repo.index()?;
+		// Other handles to the repo might have written the index in the meantime
+		index.read(true)?;
+		index.add_all(["*"], IndexAddOption::DEFAULT, None)?;
+		// add_all won't stage removed files
+		index.update_all(["*"], None)?;
+		index.write()?;
+
+		let head_tree = self.repo.head()?.peel_to_tree()?;
+		let diff = self.repo.diff_tree_to_index(Some(&head_tree), Some(&index), None)?;
+		Ok(diff.deltas().count() > 0)
+	}
+
+	fn switch_to_wip(&self) -> Result<()> {
+		let names = self.names()?;
+		let head_commit = self.repo.head()?.peel_to_commit()?;
+		match self.repo.find_branch(&names.wip, BranchType::Local) {
+			Ok(branch) if branch.get().target() != Some(head_commit.id()) => {
+				return Err(MobError::Branch(format!("{} already exists, take it instead", names.wip)).into());
+			}
+			Ok(_) => {}
+			Err(_) => {
+				self.repo.branch(&names.wip, &head_commit, false)?;
+			}
+		}
+		self.record_base(&names)?;
+		// Both branches point to the same commit, so the working tree can stay as is
+		Ok(self.repo.set_head(&Self::local_ref(&names.wip))?)
+	}
+
+	fn push(&self) -> Result<()> {
+		let wip_ref = Self::local_ref(&self.names()?.wip);
+		remote::push(&self.repo, &self.remote, &[format!("{wip_ref}:{wip_ref}")])
+	}
+
+	fn take(&self) -> Result<()> {
+		let names = self.names()?;
+		let tracking_ref = self.tracking_ref(&names.wip);
+		remote::fetch(
+			&self.repo,
+			&self.remote,
+			&[format!("+{}:{tracking_ref}", Self::local_ref(&names.wip))],
+		)?;
+		let commit = self
+			.repo
+			.find_reference(&tracking_ref)
+			.and_then(|reference| reference.peel_to_commit())
+			.map_err(|_| MobError::Branch(format!("{} not found in {}", names.wip, self.remote)))?;
+
+		// Refuses to overwrite local changes
+		self.repo
+			.checkout_tree(commit.as_object(), Some(CheckoutBuilder::new().safe()))?;
+		self.repo
+			.reference(&Self::local_ref(&names.wip), commit.id(), true, "co-author: take")?;
+		self.record_base(&names)?;
+		Ok(self.repo.set_head(&Self::local_ref(&names.wip))?)
+	}
+
+	fn squash_into_base(&self) -> Result<Vec<String>> {
+		let names = self.names()?;
+		if self.current_branch()? != names.wip {
+			return Err(MobError::Branch(format!("Not on {}", names.wip)).into());
+		}
+		let wip_commit = self.repo.head()?.peel_to_commit()?;
+		let base_commit = self
+			.repo
+			.find_branch(&names.base, BranchType::Local)
+			.and_then(|branch| branch.get().peel_to_commit())
+			.map_err(|_| MobError::Branch(format!("Base branch {} not found", names.base)))?;
+		if wip_commit.id() != base_commit.id() && !self.repo.graph_descendant_of(wip_commit.id(), base_commit.id())? {
+			return Err(MobError::Branch(format!("{} has diverged from {}", names.wip, names.base)).into());
+		}
+
+		let mut revwalk = self.repo.revwalk()?;
+		revwalk.push(wip_commit.id())?;
+		revwalk.hide(base_commit.id())?;
+		revwalk.set_sorting(Sort::TOPOLOGICAL | Sort::REVERSE)?;
+		let messages = revwalk
+			.map(|oid| Ok(self.repo.find_commit(oid?)?.message().unwrap_or_default().to_string()))
+			.collect::<Result<Vec<_>>>()?;
+
+		// Leaves the index untouched, so all of the mob's work shows up as staged changes on top of base
+		self.repo.set_head(&Self::local_ref(&names.base))?;
+		Ok(messages)
+	}
+
+	/// Undoes `squash_into_base`, the index still matching the wip branch
+	fn return_to_wip(&self) -> Result<()> {
+		let names = self.names()?;
+		Ok(self.repo.set_head(&Self::local_ref(&names.wip))?)
+	}
+
+	fn delete(&self) -> Result<()> {
+		let names = self.names()?;
+		if let Ok(mut branch) = self.repo.find_branch(&names.wip, BranchType::Local) {
+			branch.delete()?;
+		}
+		if let Ok(mut reference) = self.repo.find_reference(&self.tracking_ref(&names.wip)) {
+			reference.delete()?;
+		}
+		remote::push(&self.repo, &self.remote, &[format!(":{}", Self::local_ref(&names.wip))])
+	}
+}
+
+impl LibGitWipBranch {
+	pub fn from(path: &PathBuf, remote: &str, prefix: &str, wip_override: Option<&String>) -> Result<Self> {
+		let repo = Repository::discover(path).map_err(|_| GitError::LibGit("Could not open git repo".to_string()))?;
+		Ok(Self {
+			repo,
+			remote: remote.to_string(),
+			prefix: prefix.to_string(),
+			wip_override: wip_override.cloned(),
+		})
+	}
+
+	fn names(&self) -> Result<WipNames> {
+		let current_branch = self.current_branch()?;
+		let recorded_base = self.repo.config()?.get_string(&Self::base_key(&current_branch)).ok();
+		WipNames::from(
+			&current_branch,
+			&self.prefix,
+			self.wip_override.as_deref(),
+			recorded_base.as_deref(),
+		)
+	}
+
+	/// Once on the wip branch its base can't be told from the current branch anymore
+	fn record_base(&self, names: &WipNames) -> Result<()> {
+		Ok(self.repo.config()?.set_str(&Self::base_key(&names.wip), &names.base)?)
+	}
+
+	fn base_key(branch: &str) -> String {
+		format!("branch.{branch}.mob-base")
+	}
+
+	fn current_branch(&self) -> Result<String> {
+		Ok(self
+			.repo
+			.head()?
+			.shorthand()
+			.ok_or_else(|| GitError::LibGit("Could not get branch name".to_string()))?
+			.to_string())
+	}
+
+	fn local_ref(branch: &str) -> String {
+		format!("refs/heads/{branch}")
+	}
+
+	fn tracking_ref(&self, branch: &str) -> String {
+		format!("refs/remotes/{}/{branch}", self.remote)
+	}
+}
@@ -0,0 +1,25 @@
+pub mod di;
+pub mod err;
+pub mod service;
+pub mod session;
+pub mod wip;
+
+mod libgit {
+	mod remote;
+	pub mod session_store;
+	pub mod wip_branch;
+
+	#[cfg(test)]
+	mod test {
+		mod helper;
+		mod session_store_should;
+		mod wip_branch_should;
+	}
+}
+
+#[cfg(test)]
+mod test {
+	mod service_should;
+	mod session_should;
+	mod wip_should;
+}
@@ -0,0 +1,95 @@
+use super::err::MobError;
+use super::session::{Session, SessionStore};
+use super::wip::WipBranch;
+use crate::authors::author::Author;
+use crate::git::CommitMessage;
+use crate::Result;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct MobService<S: SessionStore, W: WipBranch> {
+	store: S,
+	wip: W,
+}
+
+impl<S: SessionStore, W: WipBranch> MobService<S, W> {
+	pub fn new(store: S, wip: W) -> Self {
+		Self { store, wip }
+	}
+
+	pub fn start(&self, authors: Vec<Author>) -> Result<Session> {
+		let session = Session::new(authors);
+		self.store.save(&session)?;
+		self.store.push()?;
+		Ok(session)
+	}
+
+	pub fn join(&self) -> Result<Session> {
+		self.store.fetch()?;
+		Ok(self.store.load()?.ok_or(MobError::NoSession)?)
+	}
+
+	pub fn stop(&self) -> Result<()> {
+		self.store.delete()?;
+		self.store.push()
+	}
+
+	pub fn current(&self) -> Option<Session> {
+		self.store.load().ok().flatten()
+	}
+
+	/// Hands the keyboard to the next author in the session, returns the new driver
+	pub fn rotate(&self) -> Result<Author> {
+		let mut session = self.current().ok_or(MobError::NoSession)?;
+		let timestamp = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map_or(0, |elapsed| elapsed.as_secs());
+		let next = session.rotate(timestamp).cloned().ok_or(MobError::NoSession)?;
+		self.store.save(&session)?;
+		self.store.push()?;
+		Ok(next)
+	}
+
+	/// Moves to the wip branch with every change staged, returns whether there is anything to commit
+	pub fn prepare_handoff(&self) -> Result<bool> {
+		self.wip.switch_to_wip()?;
+		self.wip.stage_all()
+	}
+
+	pub fn finish_handoff(&self) -> Result<()> {
+		self.wip.push()?;
+		// Pushing without a local session would delete the shared one
+		if self.current().is_some() {
+			self.store.push()?;
+		}
+		Ok(())
+	}
+
+	pub fn take(&self) -> Result<Option<Session>> {
+		self.store.fetch()?;
+		self.wip.take()?;
+		Ok(self.current())
+	}
+
+	/// Moves back to the base branch with the wip commits as staged changes, returns their co-authors
+	pub fn prepare_done(&self) -> Result<Vec<String>> {
+		self.wip.stage_all()?;
+		let mut co_authors: Vec<String> = Vec::new();
+		for message in self.wip.squash_into_base()? {
+			for author in CommitMessage::from(&message).authors() {
+				if !co_authors.contains(&author) {
+					co_authors.push(author);
+				}
+			}
+		}
+		Ok(co_authors)
+	}
+
+	/// Back on the wip branch when the squashed commit could not be made
+	pub fn abort_done(&self) -> Result<()> {
+		self.wip.return_to_wip()
+	}
+
+	pub fn finish_done(&self) -> Result<()> {
+		self.wip.delete()
+	}
+}
@@ -0,0 +1,130 @@
+use crate::authors::author::{Author, AuthorsProvider};
+use crate::authors::csv::mapper;
+use crate::Result;
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Session {
+	authors: Vec<Author>,
+	rotations: Vec<Rotation>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Rotation {
+	timestamp: u64,
+	driver_email: String,
+}
+
+impl Session {
+	pub fn new(authors: Vec<Author>) -> Self {
+		Self {
+			authors,
+			rotations: Vec::new(),
+		}
+	}
+
+	pub fn from(authors: &str, rotations: &str) -> Self {
+		let authors = non_empty_lines(authors).filter_map(mapper::to_author).collect();
+		let rotations = non_empty_lines(rotations).filter_map(Rotation::from).collect();
+
+		Self { authors, rotations }
+	}
+
+	pub fn authors(&self) -> &[Author] {
+		&self.authors
+	}
+
+	/// The authors order is the driver order, starting with the first one
+	pub fn driver(&self) -> Option<&Author> {
+		self.rotations
+			.last()
+			.and_then(|rotation| {
+				self.authors
+					.iter()
+					.find(|author| author.email() == rotation.driver_email)
+			})
+			.or_else(|| self.authors.first())
+	}
+
+	/// Only set once the timer has handed the keyboard to someone
+	pub fn rotated_driver(&self) -> Option<&Author> {
+		if self.rotations.is_empty() {
+			None
+		} else {
+			self.driver()
+		}
+	}
+
+	pub fn rotate(&mut self, timestamp: u64) -> Option<&Author> {
+		let current = self
+			.driver()
+			.and_then(|driver| self.authors.iter().position(|author| author == driver))?;
+		let next = &self.authors[(current + 1) % self.authors.len()];
+		self.rotations.push(Rotation {
+			timestamp,
+			driver_email: next.email(),
+		});
+		Some(next)
+	}
+
+	pub fn formatted_authors(&self) -> String {
+		self.authors.iter().map(mapper::to_line).collect::<Vec<_>>().join("\n")
+	}
+
+	pub fn formatted_rotations(&self) -> String {
+		self.rotations
+			.iter()
+			.map(Rotation::to_line)
+			.collect::<Vec<_>>()
+			.join("\n")
+	}
+}
+
+impl Rotation {
+	fn from(line: &str) -> Option<Self> {
+		let (timestamp, driver_email) = line.split_once(',')?;
+		Some(Self {
+			timestamp: timestamp.parse().ok()?,
+			driver_email: driver_email.to_string(),
+		})
+	}
+
+	fn to_line(&self) -> String {
+		format!("{},{}", self.timestamp, self.driver_email)
+	}
+}
+
+fn non_empty_lines(content: &str) -> impl Iterator<Item = &str> {
+	content.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+// Lets teammates without an authors file pick from the session members
+impl AuthorsProvider for Session {
+	fn find_by_aliases(&self, aliases: &[String]) -> Vec<Author> {
+		self.authors
+			.iter()
+			.filter(|author| aliases.contains(&author.alias()))
+			.cloned()
+			.collect()
+	}
+
+	fn find_by_hashes(&self, hashes: &[u64]) -> Vec<Author> {
+		self.authors
+			.iter()
+			.filter(|author| hashes.contains(&author.hash()))
+			.cloned()
+			.collect()
+	}
+
+	fn all(&self) -> Vec<Author> {
+		self.authors.clone()
+	}
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait SessionStore {
+	fn load(&self) -> Result<Option<Session>>;
+	fn save(&self, session: &Session) -> Result<()>;
+	fn delete(&self) -> Result<()>;
+	fn push(&self) -> Result<()>;
+	fn fetch(&self) -> Result<()>;
+}
@@ -0,0 +1,238 @@
+use crate::authors::author::Author;
+use crate::error::{assert_error_contains_msg, assert_error_type};
+use crate::mob::err::MobError;
+use crate::mob::service::MobService;
+use crate::mob::session::{MockSessionStore, Session};
+use crate::mob::wip::MockWipBranch;
+use mockall::Sequence;
+
+const ERR_MSG: &str = "an error";
+
+#[test]
+fn save_and_push_a_new_session() {
+	let mut mock_store = MockSessionStore::new();
+	let mut seq = Sequence::new();
+	mock_store
+		.expect_save()
+		.withf(|session| session.authors().len() == 1)
+		.times(1)
+		.returning(|_| Ok(()))
+		.in_sequence(&mut seq);
+	mock_store
+		.expect_push()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).start(vec![Author::from(
+		"a",
+		"alice",
+		"alice@wonderland.not",
+	)]);
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn not_push_a_session_that_could_not_be_saved() {
+	let mut mock_store = MockSessionStore::new();
+	mock_store.expect_save().returning(|_| Err(ERR_MSG.into()));
+	mock_store.expect_push().times(0);
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).start(vec![]);
+
+	assert_error_contains_msg(&result, ERR_MSG);
+}
+
+#[test]
+fn fetch_before_loading_when_joining() {
+	let mut mock_store = MockSessionStore::new();
+	let mut seq = Sequence::new();
+	mock_store
+		.expect_fetch()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+	mock_store
+		.expect_load()
+		.times(1)
+		.returning(|| Ok(Some(Session::default())))
+		.in_sequence(&mut seq);
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).join();
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn report_missing_session_when_joining() {
+	let mut mock_store = MockSessionStore::new();
+	mock_store.expect_fetch().returning(|| Ok(()));
+	mock_store.expect_load().returning(|| Ok(None));
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).join();
+
+	assert_error_type(&result, &MobError::NoSession);
+}
+
+#[test]
+fn delete_and_push_when_stopping() {
+	let mut mock_store = MockSessionStore::new();
+	let mut seq = Sequence::new();
+	mock_store
+		.expect_delete()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+	mock_store
+		.expect_push()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).stop();
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn have_no_current_session_when_it_cannot_be_loaded() {
+	let mut mock_store = MockSessionStore::new();
+	mock_store.expect_load().returning(|| Err(ERR_MSG.into()));
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).current();
+
+	assert!(result.is_none());
+}
+
+#[test]
+fn save_and_push_the_rotated_session() {
+	let mut mock_store = MockSessionStore::new();
+	let mut seq = Sequence::new();
+	mock_store.expect_load().returning(|| {
+		Ok(Some(Session::new(vec![
+			Author::from("a", "alice", "alice@wonderland.not"),
+			Author::from("b", "bob", "bob@builder.not"),
+		])))
+	});
+	mock_store
+		.expect_save()
+		.withf(|session| session.driver().is_some_and(|driver| driver.alias() == "b"))
+		.times(1)
+		.returning(|_| Ok(()))
+		.in_sequence(&mut seq);
+	mock_store
+		.expect_push()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).rotate();
+
+	assert!(matches!(result, Ok(next) if next.alias() == "b"));
+}
+
+#[test]
+fn not_rotate_without_a_session() {
+	let mut mock_store = MockSessionStore::new();
+	mock_store.expect_load().returning(|| Ok(None));
+	mock_store.expect_save().times(0);
+
+	let result = MobService::new(mock_store, MockWipBranch::new()).rotate();
+
+	assert_error_type(&result, &MobError::NoSession);
+}
+
+#[test]
+fn stage_everything_on_the_wip_branch_before_handing_off() {
+	let mut mock_wip = MockWipBranch::new();
+	let mut seq = Sequence::new();
+	mock_wip
+		.expect_switch_to_wip()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+	mock_wip
+		.expect_stage_all()
+		.times(1)
+		.returning(|| Ok(true))
+		.in_sequence(&mut seq);
+
+	let result = MobService::new(MockSessionStore::new(), mock_wip).prepare_handoff();
+
+	assert!(matches!(result, Ok(true)));
+}
+
+#[test]
+fn push_the_session_alongside_the_wip_branch() {
+	let mut mock_store = MockSessionStore::new();
+	let mut mock_wip = MockWipBranch::new();
+	mock_wip.expect_push().times(1).returning(|| Ok(()));
+	mock_store.expect_load().returning(|| Ok(Some(Session::default())));
+	mock_store.expect_push().times(1).returning(|| Ok(()));
+
+	let result = MobService::new(mock_store, mock_wip).finish_handoff();
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn not_push_a_missing_session_alongside_the_wip_branch() {
+	let mut mock_store = MockSessionStore::new();
+	let mut mock_wip = MockWipBranch::new();
+	mock_wip.expect_push().times(1).returning(|| Ok(()));
+	mock_store.expect_load().returning(|| Ok(None));
+	mock_store.expect_push().times(0);
+
+	let result = MobService::new(mock_store, mock_wip).finish_handoff();
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn fetch_the_session_when_taking() {
+	let mut mock_store = MockSessionStore::new();
+	let mut mock_wip = MockWipBranch::new();
+	let mut seq = Sequence::new();
+	mock_store
+		.expect_fetch()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+	mock_wip
+		.expect_take()
+		.times(1)
+		.returning(|| Ok(()))
+		.in_sequence(&mut seq);
+	mock_store.expect_load().returning(|| Ok(Some(Session::default())));
+
+	let result = MobService::new(mock_store, mock_wip).take();
+
+	assert!(matches!(result, Ok(Some(_))));
+}
+
+#[test]
+fn return_to_the_wip_branch_when_aborting_done() {
+	let mut mock_wip = MockWipBranch::new();
+	mock_wip.expect_return_to_wip().times(1).returning(|| Ok(()));
+	mock_wip.expect_delete().never();
+
+	let result = MobService::new(MockSessionStore::new(), mock_wip).abort_done();
+
+	assert!(result.is_ok());
+}
+
+#[test]
+fn keep_every_co_author_of_the_squashed_commits() {
+	let alice = "Co-authored-by: alice <alice@wonderland.not>";
+	let bob = "Co-authored-by: bob <bob@builder.not>";
+	let mut mock_wip = MockWipBranch::new();
+	mock_wip.expect_stage_all().returning(|| Ok(true));
+	mock_wip
+		.expect_squash_into_base()
+		.returning(move || Ok(vec![format!("wip\n\n{alice}"), format!("wip\n\n{bob}\n{alice}")]));
+
+	let result = MobService::new(MockSessionStore::new(), mock_wip).prepare_done();
+
+	assert!(matches!(result, Ok(co_authors) if co_authors == [alice, bob]));
+}
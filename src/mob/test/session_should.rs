@@ -0,0 +1,148 @@
+use crate::authors::author::{Author, AuthorsProvider};
+use crate::mob::session::Session;
+
+#[test]
+fn format_authors_as_csv_lines() {
+	let session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+
+	assert_eq!(
+		session.formatted_authors(),
+		"a,alice,alice@wonderland.not\nb,bob,bob@builder.not"
+	);
+}
+
+#[test]
+fn parse_authors_from_csv_lines() {
+	let session = Session::from("a,alice,alice@wonderland.not\n\n  b,bob,bob@builder.not  \n", "");
+
+	assert_eq!(
+		session.authors(),
+		[
+			Author::from("a", "alice", "alice@wonderland.not"),
+			Author::from("b", "bob", "bob@builder.not"),
+		]
+	);
+}
+
+#[test]
+fn skip_invalid_lines_when_parsing() {
+	let session = Session::from("a,alice,alice@wonderland.not\nnot an author\n", "");
+
+	assert_eq!(session.authors().len(), 1);
+}
+
+#[test]
+fn be_equal_after_a_round_trip() {
+	let mut session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+	session.rotate(1);
+
+	assert_eq!(
+		Session::from(&session.formatted_authors(), &session.formatted_rotations()),
+		session
+	);
+}
+
+#[test]
+fn start_with_the_first_author_as_driver() {
+	let session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+
+	assert_eq!(
+		session.driver(),
+		Some(&Author::from("a", "alice", "alice@wonderland.not"))
+	);
+}
+
+#[test]
+fn rotate_drivers_in_order() {
+	let mut session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+
+	let next = session.rotate(1).cloned();
+
+	assert_eq!(next, Some(Author::from("b", "bob", "bob@builder.not")));
+	assert_eq!(session.driver(), next.as_ref());
+}
+
+#[test]
+fn go_back_to_the_first_driver_after_the_last_one() {
+	let mut session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+	session.rotate(1);
+
+	let next = session.rotate(2).cloned();
+
+	assert_eq!(next, Some(Author::from("a", "alice", "alice@wonderland.not")));
+}
+
+#[test]
+fn record_every_rotation() {
+	let mut session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+	session.rotate(1);
+	session.rotate(2);
+
+	assert_eq!(
+		session.formatted_rotations(),
+		"1,bob@builder.not\n2,alice@wonderland.not"
+	);
+}
+
+#[test]
+fn only_report_a_rotated_driver_once_rotated() {
+	let mut session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+	assert!(session.rotated_driver().is_none());
+
+	session.rotate(1);
+
+	assert_eq!(
+		session.rotated_driver(),
+		Some(&Author::from("b", "bob", "bob@builder.not"))
+	);
+}
+
+#[test]
+fn not_rotate_without_authors() {
+	let mut session = Session::default();
+
+	assert!(session.rotate(1).is_none());
+}
+
+#[test]
+fn provide_its_authors_by_alias() {
+	let session = Session::new(vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+	]);
+
+	let found = session.find_by_aliases(&["b".to_string()]);
+
+	assert_eq!(found, [Author::from("b", "bob", "bob@builder.not")]);
+}
+
+#[test]
+fn provide_its_authors_by_hash() {
+	let alice = Author::from("a", "alice", "alice@wonderland.not");
+	let session = Session::new(vec![alice.clone(), Author::from("b", "bob", "bob@builder.not")]);
+
+	let found = session.find_by_hashes(&[alice.hash()]);
+
+	assert_eq!(found, [alice]);
+}
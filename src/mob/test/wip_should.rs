@@ -0,0 +1,54 @@
+use crate::error::assert_error_type;
+use crate::mob::err::MobError;
+use crate::mob::wip::WipNames;
+
+const PREFIX: &str = "mob/";
+
+#[test]
+fn prefix_the_current_branch() {
+	let names = WipNames::from("main", PREFIX, None, None);
+
+	assert!(matches!(names, Ok(names) if names.base == "main" && names.wip == "mob/main"));
+}
+
+#[test]
+fn recognize_being_on_the_wip_branch() {
+	let names = WipNames::from("mob/main", PREFIX, None, None);
+
+	assert!(matches!(names, Ok(names) if names.base == "main" && names.wip == "mob/main"));
+}
+
+#[test]
+fn use_the_given_wip_branch() {
+	let names = WipNames::from("main", PREFIX, Some("shared"), None);
+
+	assert!(matches!(names, Ok(names) if names.base == "main" && names.wip == "shared"));
+}
+
+#[test]
+fn not_use_the_base_branch_as_wip() {
+	let names = WipNames::from("main", PREFIX, Some("main"), None);
+
+	assert_error_type(&names, &MobError::Branch(String::new()));
+}
+
+#[test]
+fn use_the_base_recorded_for_the_given_wip_branch() {
+	let names = WipNames::from("shared", PREFIX, Some("shared"), Some("main"));
+
+	assert!(matches!(names, Ok(names) if names.base == "main" && names.wip == "shared"));
+}
+
+#[test]
+fn prefer_the_recorded_base_over_the_prefixed_name() {
+	let names = WipNames::from("mob/main", PREFIX, None, Some("develop"));
+
+	assert!(matches!(names, Ok(names) if names.base == "develop" && names.wip == "mob/main"));
+}
+
+#[test]
+fn not_guess_the_base_of_the_given_wip_branch() {
+	let names = WipNames::from("shared", PREFIX, Some("shared"), None);
+
+	assert_error_type(&names, &MobError::Branch(String::new()));
+}
@@ -0,0 +1,52 @@
+use super::err::MobError;
+use crate::Result;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait WipBranch {
+	fn stage_all(&self) -> Result<bool>;
+	fn switch_to_wip(&self) -> Result<()>;
+	fn push(&self) -> Result<()>;
+	fn take(&self) -> Result<()>;
+	fn squash_into_base(&self) -> Result<Vec<String>>;
+	fn return_to_wip(&self) -> Result<()>;
+	fn delete(&self) -> Result<()>;
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WipNames {
+	pub base: String,
+	pub wip: String,
+}
+
+impl WipNames {
+	/// The base is the current branch, unless on the wip branch where the base recorded for it is used instead.
+	/// Without any, a prefixed wip branch tells its base by its name.
+	pub fn from(
+		current_branch: &str,
+		prefix: &str,
+		wip_override: Option<&str>,
+		recorded_base: Option<&str>,
+	) -> Result<Self> {
+		let on_wip = match wip_override {
+			Some(wip) => current_branch == wip,
+			None => current_branch.starts_with(prefix),
+		};
+		let base = if on_wip {
+			recorded_base
+				.or_else(|| current_branch.strip_prefix(prefix))
+				.ok_or_else(|| MobError::Branch(format!("Unknown base branch for {current_branch}")))?
+				.to_string()
+		} else {
+			current_branch.to_string()
+		};
+		let wip = match wip_override {
+			_ if on_wip => current_branch.to_string(),
+			Some(wip) => wip.to_string(),
+			None => format!("{prefix}{base}"),
+		};
+		if base == wip {
+			return Err(MobError::Branch(format!("{wip} cannot be both the base and the wip branch")).into());
+		}
+		Ok(Self { base, wip })
+	}
+}
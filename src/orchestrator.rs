@@ -1,110 +1,574 @@
 use crate::{
 	args::Args,
-	authors::author::{Author, AuthorsProvider},
-	git::{commit_mode::CommitMode, di::Service},
-	ui::cli::Cli,
+	authors::{
+		author::{Author, AuthorsProvider},
+		err::AuthorsError,
+		role::RoleAlias,
+		template::SignatureTemplate,
+	},
+	common::conf,
+	git::{
+		commit_mode::CommitMode, conventional::Conventional, di::Service, err::GitError, identity::Identity,
+		lint::LintRules, preview::Pending, CommitMessage,
+	},
+	mob::{di::Mob, err::MobError},
+	pairs::{history::History, matrix::PairingMatrix, report, suggestion::Suggestion},
+	rota::partners::Rota,
+	templates::{err::TemplatesError, library::Templates},
+	ui::{
+		cli::{Cli, Decision},
+		err::UiError,
+	},
 	Result,
 };
+use std::io::IsTerminal;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const DAY_IN_SECONDS: u64 = 24 * 60 * 60;
+
+/// What comes after the message is written
+enum Review {
+	Committed,
+	Rejected(CommitMessage),
+	EditMessage(CommitMessage),
+	EditCoAuthors(CommitMessage),
+}
 
 pub struct Orchestrator {
 	args: Args,
 	cli: Cli,
-	service: Service,
 	provider: Box<dyn AuthorsProvider>,
+	mob: Mob,
+	rota: Option<Rota>,
+	templates: Option<Templates>,
+	signature_template: SignatureTemplate,
 }
 
 impl Orchestrator {
-	pub fn exec(args: Args, cli: Cli, service: Service, provider: Box<dyn AuthorsProvider>) -> Result<()> {
+	#[allow(clippy::too_many_arguments)]
+	pub fn exec(
+		args: Args,
+		cli: Cli,
+		service: Service,
+		provider: Box<dyn AuthorsProvider>,
+		mob: Mob,
+		signature_template: SignatureTemplate,
+		rota: Option<Rota>,
+		templates: Option<Templates>,
+	) -> Result<()> {
 		let mut orch = Self {
 			args,
 			cli,
-			service,
 			provider,
+			mob,
+			rota,
+			templates,
+			signature_template,
 		};
 		let authors_signatures = orch.get_authors()?;
-		orch.commit(authors_signatures)
+		orch.commit(service, authors_signatures)
+	}
+
+	pub fn start_mob(
+		args: Args,
+		cli: Cli,
+		provider: Box<dyn AuthorsProvider>,
+		mob: Mob,
+		signature_template: SignatureTemplate,
+		rota: Option<Rota>,
+	) -> Result<()> {
+		let mut orch = Self {
+			args,
+			cli,
+			provider,
+			mob,
+			rota,
+			templates: None,
+			signature_template,
+		};
+		let authors = orch.select_authors()?;
+		let session = orch.mob.start(authors)?;
+		Cli::show_authors("Started mob session with:", session.authors());
+		Ok(())
+	}
+
+	pub fn join_mob(mob: &Mob) -> Result<()> {
+		let session = mob.join()?;
+		Cli::show_authors("Joined mob session with:", session.authors());
+		Ok(())
+	}
+
+	pub fn stop_mob(mob: &Mob) -> Result<()> {
+		mob.stop()
+	}
+
+	pub fn mob_timer(cli: &Cli, mob: &Mob, minutes: u64, bell: bool, notify_cmd: &str) -> Result<()> {
+		let session = mob.current().ok_or(MobError::NoSession)?;
+		let driver = session.driver().ok_or(MobError::NoSession)?;
+		Cli::countdown(Duration::from_secs(minutes.saturating_mul(60)), driver);
+
+		let next = mob.rotate()?;
+		Cli::announce_driver(&next, bell);
+		if notify_cmd.is_empty() {
+			Ok(())
+		} else {
+			cli.notify(notify_cmd, &format!("Next driver: {} ({})", next.alias(), next.name()))
+		}
+	}
+
+	pub fn handoff(
+		args: Args,
+		cli: Cli,
+		provider: Box<dyn AuthorsProvider>,
+		mob: Mob,
+		signature_template: SignatureTemplate,
+		init_service: fn() -> Result<Service>,
+	) -> Result<()> {
+		let mut orch = Self {
+			args,
+			cli,
+			provider,
+			mob,
+			rota: None,
+			templates: None,
+			signature_template,
+		};
+		if orch.mob.prepare_handoff()? {
+			// Work in progress is squashed later on, only the final message is checked
+			let mut service = init_service()?
+				.with_lint_rules(LintRules::default())
+				.without_conventional();
+			let authors_signatures = orch.get_authors()?;
+			let (driver, authors_signatures) = orch.credit_driver(&service, authors_signatures)?;
+			service.commit(CommitMode::WithoutEditor {
+				message: &conf::wip_commit_message(),
+				authors: authors_signatures,
+				amend: false,
+				driver,
+				cleanup: None,
+			})?;
+		}
+		orch.mob.finish_handoff()
+	}
+
+	pub fn take(mob: &Mob) -> Result<()> {
+		if let Some(session) = mob.take()? {
+			Cli::show_authors("Took over mob session with:", session.authors());
+		}
+		Ok(())
+	}
+
+	pub fn done(
+		args: Args,
+		cli: Cli,
+		provider: Box<dyn AuthorsProvider>,
+		mob: Mob,
+		signature_template: SignatureTemplate,
+		templates: Option<Templates>,
+		init_service: fn() -> Result<Service>,
+	) -> Result<()> {
+		let mut orch = Self {
+			args,
+			cli,
+			provider,
+			mob,
+			rota: None,
+			templates,
+			signature_template,
+		};
+		let squashed_co_authors = orch.mob.prepare_done()?;
+		if let Err(e) = orch.commit_squashed(squashed_co_authors, init_service) {
+			orch.mob.abort_done()?;
+			return Err(e);
+		}
+		orch.mob.finish_done()
+	}
+
+	fn commit_squashed(
+		&mut self,
+		mut authors_signatures: Vec<String>,
+		init_service: fn() -> Result<Service>,
+	) -> Result<()> {
+		for signature in self.get_authors()? {
+			if !authors_signatures.contains(&signature) {
+				authors_signatures.push(signature);
+			}
+		}
+		self.commit(init_service()?, authors_signatures)
+	}
+
+	#[allow(clippy::too_many_arguments)]
+	pub fn suggest_pairs(
+		args: Args,
+		cli: Cli,
+		provider: Box<dyn AuthorsProvider>,
+		mob: Mob,
+		signature_template: SignatureTemplate,
+		history: &impl History,
+		days: u64,
+		json: bool,
+	) -> Result<()> {
+		let mut orch = Self {
+			args,
+			cli,
+			provider,
+			mob,
+			rota: None,
+			templates: None,
+			signature_template,
+		};
+		let authors = orch.select_authors()?;
+		let now = SystemTime::now()
+			.duration_since(UNIX_EPOCH)
+			.map_or(0, |elapsed| i64::try_from(elapsed.as_secs()).unwrap_or(i64::MAX));
+		let window = i64::try_from(days.saturating_mul(DAY_IN_SECONDS)).unwrap_or(i64::MAX);
+
+		let matrix = PairingMatrix::from(&history.since(now.saturating_sub(window))?);
+		let suggestion = Suggestion::for_authors(&authors, &matrix);
+		if json {
+			Cli::show(&report::json(&suggestion, &authors, &matrix, days));
+		} else {
+			Cli::show(&report::human(&suggestion, &authors, &matrix, days, now));
+		}
+		Ok(())
 	}
 
 	fn get_authors(&mut self) -> Result<Vec<String>> {
+		let authors = match self.mob.current() {
+			Some(session) if !self.args.selects_authors() => session.authors().to_vec(),
+			_ => self.select_authors()?,
+		};
+		self.signatures(&authors)
+	}
+
+	/// The co-authors signatures, followed by those of other roles and the given trailers
+	fn signatures(&self, authors: &[Author]) -> Result<Vec<String>> {
+		let mut signatures: Vec<_> = authors
+			.iter()
+			.map(|author| author.signature(&self.signature_template))
+			.collect();
+		if self.args.sort {
+			signatures.sort();
+		}
+		signatures.extend(self.role_signatures()?);
+		signatures.extend(self.args.trailer.iter().cloned());
+		Ok(signatures)
+	}
+
+	fn select_authors(&mut self) -> Result<Vec<Author>> {
 		let all_authors = self.provider.all();
 		if self.args.all {
-			let all_signatures: Vec<_> = all_authors.iter().map(Author::signature).collect();
-			return if self.args.sort {
-				Ok(Self::sort(all_signatures))
-			} else {
-				Ok(all_signatures)
-			};
+			return Ok(all_authors.into_iter().filter(Author::is_active).collect());
 		}
 
 		if self.args.fzf {
-			let found_authors: Vec<_> = self
-				.provider
-				.find_by_hashes(&self.cli.fzf_prompt(&all_authors)?)
-				.iter()
-				.map(Author::signature)
-				.collect();
-			return if self.args.sort {
-				Ok(Self::sort(found_authors))
-			} else {
-				Ok(found_authors)
-			};
+			return Ok(self.provider.find_by_hashes(&self.cli.fzf_prompt(&all_authors)?));
+		}
+
+		let aliases = if let Some(list) = &self.args.list {
+			// Role-qualified aliases are credited through role_signatures instead
+			list.split(',')
+				.filter(|alias| !alias.contains(':'))
+				.map(ToString::to_string)
+				.collect::<Vec<String>>()
+		} else {
+			let preselected = self.rota_partners(&all_authors);
+			self.cli.aliases_prompt(&all_authors, &preselected)?
+		};
+		Ok(self.provider.find_by_aliases(&aliases))
+	}
+
+	/// Authors credited with another trailer, either as `role:alias` in the list or through `--reviewed-by`
+	fn role_signatures(&self) -> Result<Vec<String>> {
+		let qualified = self.args.list.iter().flat_map(|list| list.split(','));
+		let mut role_aliases = Vec::new();
+		for entry in qualified {
+			role_aliases.extend(RoleAlias::parse(entry)?);
 		}
+		let reviewers = self.args.reviewed_by.iter().flat_map(|list| list.split(','));
+		role_aliases.extend(reviewers.map(|alias| RoleAlias::from("Reviewed-by", alias.trim())));
+
+		let mut signatures = Vec::new();
+		for role_alias in role_aliases {
+			let authors = self.provider.find_by_aliases(&[role_alias.alias().to_string()]);
+			if authors.is_empty() {
+				return Err(AuthorsError::UnknownAlias(role_alias.alias().to_string()).into());
+			}
+			signatures.extend(
+				authors
+					.iter()
+					.map(|author| author.signature_as(role_alias.key(), &self.signature_template)),
+			);
+		}
+		Ok(signatures)
+	}
 
-		let aliases = match &self.args.list {
-			Some(list) => list.split(',').map(ToString::to_string).collect::<Vec<String>>(),
-			None => self.cli.aliases_prompt(&all_authors)?,
+	fn sign_off(&self, service: &Service, mut signatures: Vec<String>) -> Result<Vec<String>> {
+		if self.args.signoff {
+			let committer = service.committer()?;
+			// The DCO expects `Name <email>`, whatever the configured template
+			signatures.push(
+				Author::from("", committer.name(), committer.email())
+					.signature_as("Signed-off-by", &SignatureTemplate::default()),
+			);
+		}
+		Ok(signatures)
+	}
+
+	/// The driver authors the commit and the machine owner (the committer) is credited as co-author instead
+	fn credit_driver(&self, service: &Service, signatures: Vec<String>) -> Result<(Option<Identity>, Vec<String>)> {
+		let Some(driver) = self.driver()? else {
+			return Ok((None, signatures));
 		};
-		let found_authors: Vec<_> = self
+		let owner = service.committer()?;
+		if driver.email() == owner.email() {
+			return Ok((None, signatures));
+		}
+
+		// Only authors from the file have an alias for the template to show
+		let owner_signature = match self
 			.provider
-			.find_by_aliases(&aliases)
-			.iter()
-			.map(Author::signature)
+			.all()
+			.into_iter()
+			.find(|author| author.email() == owner.email())
+		{
+			Some(author) => author.signature(&self.signature_template),
+			None => Author::from("", owner.name(), owner.email()).signature(&SignatureTemplate::default()),
+		};
+		let mut signatures: Vec<_> = signatures
+			.into_iter()
+			.filter(|signature| *signature != driver.signature(&self.signature_template))
 			.collect();
-
+		// Among the co-authors, ahead of the other roles and trailers
+		if !signatures.contains(&owner_signature) {
+			signatures.insert(co_authors_end(&signatures), owner_signature);
+		}
 		if self.args.sort {
-			Ok(Self::sort(found_authors))
-		} else {
-			Ok(found_authors)
+			let end = co_authors_end(&signatures);
+			signatures[..end].sort();
 		}
+		Ok((Some(Identity::from(&driver.name(), &driver.email())), signatures))
 	}
 
-	fn commit(&mut self, authors_signatures: Vec<String>) -> Result<()> {
-		if self.args.amend {
+	fn driver(&self) -> Result<Option<Author>> {
+		match &self.args.driver {
+			Some(alias) => self
+				.provider
+				.find_by_aliases(std::slice::from_ref(alias))
+				.into_iter()
+				.next()
+				.map(Some)
+				.ok_or_else(|| AuthorsError::UnknownAlias(alias.clone()).into()),
+			None => Ok(self.mob.current().and_then(|session| session.rotated_driver().cloned())),
+		}
+	}
+
+	fn rota_partners(&self, authors: &[Author]) -> Vec<String> {
+		let Some(rota) = &self.rota else {
+			return Vec::new();
+		};
+		let partners = rota.partners(authors);
+		if !partners.unknown.is_empty() {
+			Cli::warn(&format!("Unknown aliases in rota: {}", partners.unknown.join(", ")));
+		}
+		partners.aliases
+	}
+
+	fn commit(&mut self, mut service: Service, authors_signatures: Vec<String>) -> Result<()> {
+		let (mut driver, mut authors_signatures) = self.credit(&service, authors_signatures)?;
+		if self.args.amend || self.args.reuse.is_some() {
 			self.args.pre_populate = true;
 		}
 
+		let conventional = self.conventional_message(&service)?;
+		let template = self.template_message()?;
+
 		if self.args.editor {
-			if self.args.pre_populate {
-				return self.service.commit(CommitMode::WithEditor {
-					message: Some(self.service.last_commit_message().as_str()),
-					authors: authors_signatures,
+			let mut message = if self.args.pre_populate {
+				Some(self.previous_message(&service)?)
+			} else {
+				conventional.or(template)
+			};
+			loop {
+				let prepared = service.prepare(CommitMode::WithEditor {
+					message: message.as_deref(),
+					authors: authors_signatures.clone(),
 					amend: self.args.amend,
+					driver: driver.clone(),
+					cleanup: self.args.cleanup,
+					verbose: self.args.verbose,
 				});
+				match self.review(&service, prepared)? {
+					Review::Committed => return Ok(()),
+					Review::Rejected(message_to_edit) | Review::EditMessage(message_to_edit) => {
+						message = Some(message_to_edit.to_string());
+					}
+					Review::EditCoAuthors(committed) => {
+						(driver, authors_signatures) = self.edit_co_authors(&service, &committed)?;
+						message = Some(committed.without_co_authors().to_string());
+					}
+				}
 			}
-			return self.service.commit(CommitMode::WithEditor {
-				message: None,
-				authors: authors_signatures,
+		}
+
+		let mut msg = if let Some(msg) = self.args.message.clone().or(conventional) {
+			msg
+		} else {
+			let initial = if self.args.pre_populate {
+				Some(self.previous_message(&service)?)
+			} else {
+				template.or_else(|| service.template_message())
+			};
+			self.cli.message_prompt(initial.as_deref())?
+		};
+		loop {
+			let prepared = service.prepare(CommitMode::WithoutEditor {
+				message: msg.as_str(),
+				authors: authors_signatures.clone(),
 				amend: self.args.amend,
+				driver: driver.clone(),
+				cleanup: self.args.cleanup,
 			});
+			match self.review(&service, prepared)? {
+				Review::Committed => return Ok(()),
+				Review::Rejected(message_to_edit) | Review::EditMessage(message_to_edit) => {
+					msg = self
+						.cli
+						.message_prompt(Some(&message_to_edit.without_co_authors().to_string()))?;
+				}
+				Review::EditCoAuthors(committed) => {
+					(driver, authors_signatures) = self.edit_co_authors(&service, &committed)?;
+					msg = committed.without_co_authors().to_string();
+				}
+			}
+		}
+	}
+
+	/// The driver and the signatures once the driver is credited and the committer signed off
+	fn credit(&self, service: &Service, signatures: Vec<String>) -> Result<(Option<Identity>, Vec<String>)> {
+		let (driver, signatures) = self.credit_driver(service, signatures)?;
+		Ok((driver, self.sign_off(service, signatures)?))
+	}
+
+	/// Selects the co-authors again, starting from those credited in the message
+	fn edit_co_authors(
+		&mut self,
+		service: &Service,
+		message: &CommitMessage,
+	) -> Result<(Option<Identity>, Vec<String>)> {
+		let all_authors = self.provider.all();
+		let credited = message.authors();
+		let mut preselected: Vec<String> = all_authors
+			.iter()
+			.filter(|author| {
+				credited
+					.iter()
+					.any(|line| line.contains(&format!("<{}>", author.email())))
+			})
+			.map(Author::alias)
+			.collect();
+		preselected.dedup();
+		let aliases = self.cli.aliases_prompt(&all_authors, &preselected)?;
+		let signatures = self.signatures(&self.provider.find_by_aliases(&aliases))?;
+		self.credit(service, signatures)
+	}
+
+	/// The message to start from with `--reuse`, or the last one
+	fn previous_message(&self, service: &Service) -> Result<String> {
+		match &self.args.reuse {
+			Some(rev) => service.commit_message(rev),
+			None => Ok(service.last_commit_message()),
+		}
+	}
+
+	/// Commits once confirmed, unless there is something to fix up or edit first
+	fn review(&mut self, service: &Service, prepared: Result<Pending>) -> Result<Review> {
+		let pending = match prepared {
+			Ok(pending) => pending,
+			Err(e) => return self.fix_up(service, e),
+		};
+		if !self.previews() {
+			return service.finish(&pending).map(|()| Review::Committed);
+		}
+		let preview = service.preview(&pending)?;
+		match self.cli.preview_prompt(&preview)? {
+			Decision::Commit => service.finish(&pending).map(|()| Review::Committed),
+			Decision::EditMessage => Ok(Review::EditMessage(preview.message)),
+			Decision::EditCoAuthors => Ok(Review::EditCoAuthors(preview.message)),
+			Decision::Abort => Err(UiError::Aborted.into()),
 		}
+	}
+
+	/// A given message can't be fixed or previewed, and neither can anything without a terminal
+	fn interactive(&self) -> bool {
+		self.args.message.is_none() && std::io::stdin().is_terminal()
+	}
 
-		let msg = match (self.args.message.clone(), self.args.pre_populate) {
-			(Some(msg), _) => msg,
-			(None, false) => self.cli.message_prompt()?,
-			(None, true) => self
-				.cli
-				.pre_populated_message_prompt(&self.service.last_commit_message())?,
+	fn previews(&self) -> bool {
+		conf::preview() && !self.args.yes && self.interactive()
+	}
+
+	/// The message that failed the checks, when it can be fixed up interactively, otherwise the error.
+	/// Missing co-authors are selected again, anything else is fixed by editing the message
+	fn fix_up(&mut self, service: &Service, e: Box<dyn crate::error::Error>) -> Result<Review> {
+		let failed_checks = e
+			.as_any()
+			.downcast_ref::<GitError>()
+			.is_some_and(|e| matches!(e, GitError::Lint(_)));
+		let Some(rejected) = service
+			.rejected_message()
+			.filter(|_| failed_checks && self.interactive())
+		else {
+			return Err(e);
 		};
 
-		self.service.commit(CommitMode::WithoutEditor {
-			message: msg.as_str(),
-			authors: authors_signatures,
-			amend: self.args.amend,
-		})
+		Cli::warn(&e.to_string());
+		if service.lacks_co_authors() {
+			if self.cli.confirm("Select the co-authors again?")? {
+				return Ok(Review::EditCoAuthors(rejected.clone()));
+			}
+		} else if self.cli.confirm("Edit the message again?")? {
+			return Ok(Review::Rejected(rejected.clone()));
+		}
+		Err(e)
 	}
 
-	fn sort<String: Ord>(mut vector: Vec<String>) -> Vec<String> {
-		vector.sort();
-		vector
+	/// Built with `--conventional`, or in place of the message prompt when Conventional Commits are enforced
+	fn conventional_message(&mut self, service: &Service) -> Result<Option<String>> {
+		let prompted =
+			!self.args.editor && self.args.message.is_none() && !self.args.pre_populate && self.args.template.is_none();
+		if !(self.args.conventional || prompted && conf::conventional_commits()) {
+			return Ok(None);
+		}
+		self.cli
+			.conventional_prompt(&Conventional::configured(), service.suggested_scope().as_deref())
+			.map(Some)
+	}
+
+	/// The template chosen with `--template`, its variables filled in
+	fn template_message(&mut self) -> Result<Option<String>> {
+		let (Some(name), Some(templates)) = (&self.args.template, &self.templates) else {
+			return Ok(None);
+		};
+		let name = if name.is_empty() {
+			self.cli
+				.template_prompt(&templates.names())?
+				.ok_or(TemplatesError::NoneSelected)?
+		} else {
+			name.clone()
+		};
+		let cli = &mut self.cli;
+		templates
+			.render(&name, |variable| cli.variable_prompt(variable))
+			.map(Some)
 	}
 }
+
+/// The co-authors signatures come first, see `signatures`
+fn co_authors_end(signatures: &[String]) -> usize {
+	let prefix = format!("{}:", conf::co_author_prefix());
+	signatures
+		.iter()
+		.take_while(|signature| signature.starts_with(&prefix))
+		.count()
+}
@@ -0,0 +1,7 @@
+use super::libgit::history::LibGitHistory;
+use crate::common::env;
+use crate::Result;
+
+pub fn init() -> Result<LibGitHistory> {
+	LibGitHistory::from(&env::cwd()?)
+}
@@ -0,0 +1,38 @@
+use crate::Result;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PairedCommit {
+	timestamp: i64,
+	emails: Vec<String>,
+}
+
+impl PairedCommit {
+	pub fn from(timestamp: i64, author_email: &str, co_author_signatures: &[String]) -> Self {
+		let mut emails = vec![author_email.to_lowercase()];
+		for email in co_author_signatures.iter().filter_map(|signature| email_in(signature)) {
+			if !emails.contains(&email) {
+				emails.push(email);
+			}
+		}
+		Self { timestamp, emails }
+	}
+
+	pub fn timestamp(&self) -> i64 {
+		self.timestamp
+	}
+
+	pub fn emails(&self) -> &[String] {
+		&self.emails
+	}
+}
+
+fn email_in(signature: &str) -> Option<String> {
+	let start = signature.rfind('<')?;
+	let end = signature.rfind('>')?;
+	(start < end).then(|| signature[start + 1..end].trim().to_lowercase())
+}
+
+#[cfg_attr(test, mockall::automock)]
+pub trait History {
+	fn since(&self, timestamp: i64) -> Result<Vec<PairedCommit>>;
+}
@@ -0,0 +1,44 @@
+use crate::git::CommitMessage;
+use crate::pairs::history::{History, PairedCommit};
+use crate::Result;
+use git2::{Repository, Sort};
+use std::path::PathBuf;
+
+pub struct LibGitHistory {
+	repo: Repository,
+}
+
+impl LibGitHistory {
+	pub fn from(path: &PathBuf) -> Result<Self> {
+		Ok(Self {
+			repo: Repository::discover(path)?,
+		})
+	}
+}
+
+impl History for LibGitHistory {
+	fn since(&self, timestamp: i64) -> Result<Vec<PairedCommit>> {
+		let mut revwalk = self.repo.revwalk()?;
+		revwalk.set_sorting(Sort::TIME)?;
+		if revwalk.push_head().is_err() {
+			// Nothing committed yet
+			return Ok(Vec::new());
+		}
+
+		let mut commits = Vec::new();
+		for oid in revwalk {
+			let commit = self.repo.find_commit(oid?)?;
+			let commit_time = commit.time().seconds();
+			if commit_time < timestamp {
+				break;
+			}
+			let message = CommitMessage::from(commit.message().unwrap_or_default());
+			commits.push(PairedCommit::from(
+				commit_time,
+				commit.author().email().unwrap_or_default(),
+				&message.authors(),
+			));
+		}
+		Ok(commits)
+	}
+}
@@ -0,0 +1,69 @@
+use crate::pairs::history::{History, PairedCommit};
+use crate::pairs::libgit::history::LibGitHistory;
+use crate::Result;
+use git2::{Repository, RepositoryInitOptions, Signature, Time};
+use std::fs;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+const TEST_DIR_PATH: &str = "/tmp/coa/pairs";
+
+#[test]
+fn read_authors_and_co_authors_of_each_commit() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = Repository::init_opts(&path, &RepositoryInitOptions::new())?;
+	commit_at(&repo, 100, "alice@x", "a commit\n\n\nCo-authored-by: Bob <bob@x>")?;
+	commit_at(&repo, 200, "bob@x", "another commit")?;
+
+	let result = LibGitHistory::from(&path)?.since(0);
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(
+		result?,
+		vec![
+			PairedCommit::from(200, "bob@x", &[]),
+			PairedCommit::from(100, "alice@x", &["Co-authored-by: Bob <bob@x>".to_string()]),
+		]
+	);
+	Ok(())
+}
+
+#[test]
+fn ignore_commits_older_than_the_given_timestamp() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	let repo = Repository::init_opts(&path, &RepositoryInitOptions::new())?;
+	commit_at(&repo, 100, "alice@x", "an old commit")?;
+	commit_at(&repo, 200, "bob@x", "a recent commit")?;
+
+	let result = LibGitHistory::from(&path)?.since(150);
+
+	fs::remove_dir_all(path).ok();
+	assert_eq!(result?, vec![PairedCommit::from(200, "bob@x", &[])]);
+	Ok(())
+}
+
+#[test]
+fn be_empty_when_nothing_was_committed() -> Result<()> {
+	let path = random_tmp_path_in(TEST_DIR_PATH);
+	Repository::init_opts(&path, &RepositoryInitOptions::new())?;
+
+	let result = LibGitHistory::from(&path)?.since(0);
+
+	fs::remove_dir_all(path).ok();
+	assert!(result?.is_empty());
+	Ok(())
+}
+
+fn commit_at(repo: &Repository, seconds: i64, email: &str, msg: &str) -> Result<()> {
+	let signature = Signature::new("a_name", email, &Time::new(seconds, 0))?;
+	let tree = repo.find_tree(repo.index()?.write_tree()?)?;
+	let parent = repo.head().and_then(|head| head.peel_to_commit()).ok();
+	let parents = parent.iter().collect::<Vec<_>>();
+	repo.commit(Some("HEAD"), &signature, &signature, msg, &tree, &parents)?;
+	Ok(())
+}
+
+fn random_tmp_path_in(path: &str) -> PathBuf {
+	let random = Uuid::new_v4();
+	PathBuf::from(format!("{path}/{random}"))
+}
@@ -0,0 +1,43 @@
+use super::history::PairedCommit;
+use std::collections::HashMap;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Pairing {
+	pub times: u32,
+	pub last: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct PairingMatrix {
+	pairings: HashMap<(String, String), Pairing>,
+}
+
+impl PairingMatrix {
+	pub fn from(commits: &[PairedCommit]) -> Self {
+		let mut pairings: HashMap<(String, String), Pairing> = HashMap::new();
+		for commit in commits {
+			let emails = commit.emails();
+			for (i, email) in emails.iter().enumerate() {
+				for other in &emails[i + 1..] {
+					let pairing = pairings.entry(key(email, other)).or_default();
+					pairing.times += 1;
+					pairing.last = pairing.last.max(Some(commit.timestamp()));
+				}
+			}
+		}
+		Self { pairings }
+	}
+
+	pub fn between(&self, email: &str, other: &str) -> Pairing {
+		self.pairings.get(&key(email, other)).copied().unwrap_or_default()
+	}
+}
+
+fn key(email: &str, other: &str) -> (String, String) {
+	let (email, other) = (email.to_lowercase(), other.to_lowercase());
+	if email <= other {
+		(email, other)
+	} else {
+		(other, email)
+	}
+}
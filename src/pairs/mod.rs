@@ -0,0 +1,21 @@
+pub mod di;
+pub mod history;
+pub mod matrix;
+pub mod report;
+pub mod suggestion;
+
+mod libgit {
+	pub mod history;
+
+	#[cfg(test)]
+	mod test {
+		mod history_should;
+	}
+}
+
+#[cfg(test)]
+mod test {
+	mod matrix_should;
+	mod report_should;
+	mod suggestion_should;
+}
@@ -0,0 +1,103 @@
+use super::matrix::{Pairing, PairingMatrix};
+use super::suggestion::Suggestion;
+use crate::authors::author::Author;
+use serde_json::{json, Map, Value};
+
+const DAY_IN_SECONDS: i64 = 24 * 60 * 60;
+
+pub fn human(suggestion: &Suggestion, authors: &[Author], matrix: &PairingMatrix, days: u64, now: i64) -> String {
+	let pairs = suggestion
+		.pairs()
+		.iter()
+		.map(|pair| {
+			let aliases = pair.authors.iter().map(Author::alias).collect::<Vec<_>>();
+			let history = if pair.authors.len() < 2 {
+				"solo".to_string()
+			} else {
+				describe(pair.pairing, now)
+			};
+			format!("  {}: {history}", aliases.join(" + "))
+		})
+		.collect::<Vec<_>>()
+		.join("\n");
+
+	format!(
+		"Suggested pairs based on the last {days} days:\n{pairs}\n\nPairing matrix:\n{}",
+		table(authors, matrix)
+	)
+}
+
+pub fn json(suggestion: &Suggestion, authors: &[Author], matrix: &PairingMatrix, days: u64) -> String {
+	let pairs = suggestion
+		.pairs()
+		.iter()
+		.map(|pair| {
+			json!({
+				"aliases": pair.authors.iter().map(Author::alias).collect::<Vec<_>>(),
+				"times_paired": pair.pairing.times,
+				"last_paired": pair.pairing.last,
+			})
+		})
+		.collect::<Vec<_>>();
+
+	let mut rows = Map::new();
+	for author in authors {
+		let mut row = Map::new();
+		for other in authors.iter().filter(|other| other.alias() != author.alias()) {
+			let times = matrix.between(&author.email(), &other.email()).times;
+			row.insert(other.alias(), Value::from(times));
+		}
+		rows.insert(author.alias(), Value::Object(row));
+	}
+
+	json!({ "days": days, "pairs": pairs, "matrix": rows }).to_string()
+}
+
+fn describe(pairing: Pairing, now: i64) -> String {
+	match pairing.last {
+		None => "never paired".to_string(),
+		Some(last) => {
+			let days_ago = (now - last).max(0) / DAY_IN_SECONDS;
+			let times = if pairing.times == 1 {
+				"once".to_string()
+			} else {
+				format!("{} times", pairing.times)
+			};
+			format!("paired {times}, last {days_ago} days ago")
+		}
+	}
+}
+
+fn table(authors: &[Author], matrix: &PairingMatrix) -> String {
+	let width = authors
+		.iter()
+		.map(|author| author.alias().len())
+		.max()
+		.unwrap_or_default();
+	let header = authors
+		.iter()
+		.map(|author| format!("{:>width$}", author.alias()))
+		.collect::<Vec<_>>()
+		.join(" ");
+
+	let rows = authors.iter().map(|author| {
+		let cells = authors
+			.iter()
+			.map(|other| {
+				let cell = if other.alias() == author.alias() {
+					"-".to_string()
+				} else {
+					matrix.between(&author.email(), &other.email()).times.to_string()
+				};
+				format!("{cell:>width$}")
+			})
+			.collect::<Vec<_>>()
+			.join(" ");
+		format!("  {:<width$} {cells}", author.alias())
+	});
+
+	std::iter::once(format!("  {:<width$} {header}", ""))
+		.chain(rows)
+		.collect::<Vec<_>>()
+		.join("\n")
+}
@@ -0,0 +1,50 @@
+use super::matrix::{Pairing, PairingMatrix};
+use crate::authors::author::Author;
+
+#[derive(Debug, PartialEq)]
+pub struct Pair {
+	pub authors: Vec<Author>,
+	pub pairing: Pairing,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Suggestion {
+	pairs: Vec<Pair>,
+}
+
+impl Suggestion {
+	/// Greedily pairs up those who paired the least (and the longest ago), leaving one solo if needed
+	pub fn for_authors(authors: &[Author], matrix: &PairingMatrix) -> Self {
+		let mut candidates = Vec::new();
+		for (i, author) in authors.iter().enumerate() {
+			for (j, other) in authors.iter().enumerate().skip(i + 1) {
+				candidates.push((i, j, matrix.between(&author.email(), &other.email())));
+			}
+		}
+		candidates.sort_by_key(|(_, _, pairing)| (pairing.times, pairing.last));
+
+		let mut paired = vec![false; authors.len()];
+		let mut pairs = Vec::new();
+		for (i, j, pairing) in candidates {
+			if !paired[i] && !paired[j] {
+				paired[i] = true;
+				paired[j] = true;
+				pairs.push(Pair {
+					authors: vec![authors[i].clone(), authors[j].clone()],
+					pairing,
+				});
+			}
+		}
+
+		let solos = authors.iter().zip(paired).filter(|(_, paired)| !paired);
+		pairs.extend(solos.map(|(author, _)| Pair {
+			authors: vec![author.clone()],
+			pairing: Pairing::default(),
+		}));
+		Self { pairs }
+	}
+
+	pub fn pairs(&self) -> &[Pair] {
+		&self.pairs
+	}
+}
@@ -0,0 +1,53 @@
+use crate::pairs::history::PairedCommit;
+use crate::pairs::matrix::{Pairing, PairingMatrix};
+
+#[test]
+fn count_every_commit_shared_by_two_people() {
+	let matrix = PairingMatrix::from(&[
+		PairedCommit::from(1, "alice@x", &["Co-authored-by: Bob <bob@x>".to_string()]),
+		PairedCommit::from(2, "bob@x", &["Co-authored-by: Alice <alice@x>".to_string()]),
+		PairedCommit::from(3, "alice@x", &[]),
+	]);
+
+	assert_eq!(
+		matrix.between("alice@x", "bob@x"),
+		Pairing {
+			times: 2,
+			last: Some(2)
+		}
+	);
+}
+
+#[test]
+fn be_symmetric_and_ignore_case() {
+	let matrix = PairingMatrix::from(&[PairedCommit::from(
+		1,
+		"Alice@X",
+		&["Co-authored-by: Bob <BOB@x>".to_string()],
+	)]);
+
+	assert_eq!(matrix.between("bob@x", "alice@x"), matrix.between("alice@x", "bob@x"));
+	assert_eq!(matrix.between("bob@x", "alice@x").times, 1);
+}
+
+#[test]
+fn pair_every_co_author_of_a_mob_commit() {
+	let matrix = PairingMatrix::from(&[PairedCommit::from(
+		1,
+		"alice@x",
+		&[
+			"Co-authored-by: Bob <bob@x>".to_string(),
+			"Co-authored-by: Erica <erica@x>".to_string(),
+		],
+	)]);
+
+	assert_eq!(matrix.between("alice@x", "erica@x").times, 1);
+	assert_eq!(matrix.between("bob@x", "erica@x").times, 1);
+}
+
+#[test]
+fn report_people_who_never_paired() {
+	let matrix = PairingMatrix::from(&[]);
+
+	assert_eq!(matrix.between("alice@x", "bob@x"), Pairing::default());
+}
@@ -0,0 +1,77 @@
+use crate::authors::author::Author;
+use crate::pairs::history::PairedCommit;
+use crate::pairs::matrix::PairingMatrix;
+use crate::pairs::report;
+use crate::pairs::suggestion::Suggestion;
+
+const DAY: i64 = 24 * 60 * 60;
+
+fn setup() -> (Vec<Author>, PairingMatrix) {
+	let authors = vec![
+		Author::from("a", "alice", "alice@x"),
+		Author::from("b", "bob", "bob@x"),
+		Author::from("c", "carol", "carol@x"),
+	];
+	let matrix = PairingMatrix::from(&[PairedCommit::from(
+		DAY,
+		"alice@x",
+		&["Co-authored-by: Bob <bob@x>".to_string()],
+	)]);
+	(authors, matrix)
+}
+
+#[test]
+fn describe_pairs_and_matrix_for_humans() {
+	let (authors, matrix) = setup();
+	let suggestion = Suggestion::for_authors(&authors, &matrix);
+
+	let result = report::human(&suggestion, &authors, &matrix, 30, 3 * DAY);
+
+	assert_eq!(
+		result,
+		"Suggested pairs based on the last 30 days:
+  a + c: never paired
+  b: solo
+
+Pairing matrix:
+    a b c
+  a - 1 0
+  b 1 - 0
+  c 0 0 -"
+	);
+}
+
+#[test]
+fn mention_when_people_last_paired() {
+	let (authors, matrix) = setup();
+	let suggestion = Suggestion::for_authors(&authors[..2], &matrix);
+
+	let result = report::human(&suggestion, &authors[..2], &matrix, 30, 3 * DAY);
+
+	assert!(result.contains("a + b: paired once, last 2 days ago"));
+}
+
+#[test]
+fn serialize_pairs_and_matrix_as_json() {
+	let (authors, matrix) = setup();
+	let suggestion = Suggestion::for_authors(&authors, &matrix);
+
+	let result = report::json(&suggestion, &authors, &matrix, 30);
+
+	let parsed: serde_json::Value = serde_json::from_str(&result).expect("valid json");
+	assert_eq!(
+		parsed,
+		serde_json::json!({
+			"days": 30,
+			"pairs": [
+				{ "aliases": ["a", "c"], "times_paired": 0, "last_paired": null },
+				{ "aliases": ["b"], "times_paired": 0, "last_paired": null },
+			],
+			"matrix": {
+				"a": { "b": 1, "c": 0 },
+				"b": { "a": 1, "c": 0 },
+				"c": { "a": 0, "b": 0 },
+			},
+		})
+	);
+}
@@ -0,0 +1,67 @@
+use crate::authors::author::Author;
+use crate::pairs::history::PairedCommit;
+use crate::pairs::matrix::PairingMatrix;
+use crate::pairs::suggestion::Suggestion;
+
+fn team() -> Vec<Author> {
+	vec![
+		Author::from("a", "alice", "alice@x"),
+		Author::from("b", "bob", "bob@x"),
+		Author::from("c", "carol", "carol@x"),
+		Author::from("d", "dave", "dave@x"),
+	]
+}
+
+fn aliases(suggestion: &Suggestion) -> Vec<Vec<String>> {
+	suggestion
+		.pairs()
+		.iter()
+		.map(|pair| pair.authors.iter().map(Author::alias).collect())
+		.collect()
+}
+
+fn paired(timestamp: i64, email: &str, other_name: &str, other_email: &str) -> PairedCommit {
+	PairedCommit::from(
+		timestamp,
+		email,
+		&[format!("Co-authored-by: {other_name} <{other_email}>")],
+	)
+}
+
+#[test]
+fn favour_people_who_have_not_paired() {
+	let matrix = PairingMatrix::from(&[
+		paired(1, "alice@x", "bob", "bob@x"),
+		paired(2, "carol@x", "dave", "dave@x"),
+	]);
+
+	let suggestion = Suggestion::for_authors(&team(), &matrix);
+
+	assert_eq!(aliases(&suggestion), vec![vec!["a", "c"], vec!["b", "d"]]);
+}
+
+#[test]
+fn favour_people_who_paired_the_longest_ago() {
+	let matrix = PairingMatrix::from(&[
+		paired(1, "alice@x", "bob", "bob@x"),
+		paired(2, "carol@x", "dave", "dave@x"),
+		paired(3, "alice@x", "carol", "carol@x"),
+		paired(4, "bob@x", "dave", "dave@x"),
+		paired(5, "alice@x", "dave", "dave@x"),
+		paired(6, "bob@x", "carol", "carol@x"),
+	]);
+
+	let suggestion = Suggestion::for_authors(&team(), &matrix);
+
+	assert_eq!(aliases(&suggestion), vec![vec!["a", "b"], vec!["c", "d"]]);
+}
+
+#[test]
+fn leave_one_solo_when_odd() {
+	let authors = &team()[..3];
+	let matrix = PairingMatrix::from(&[paired(1, "alice@x", "bob", "bob@x")]);
+
+	let suggestion = Suggestion::for_authors(authors, &matrix);
+
+	assert_eq!(aliases(&suggestion), vec![vec!["a", "c"], vec!["b"]]);
+}
@@ -0,0 +1,87 @@
+use git2::{Signature, Time};
+use std::fmt::Display;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DAY_IN_SECONDS: i64 = 24 * 60 * 60;
+
+/// A calendar day, stored as days since the unix epoch
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Date {
+	days: i64,
+}
+
+impl Date {
+	/// In the local time zone, as git would date a commit made now, falling back to UTC
+	pub fn today() -> Self {
+		Signature::now("today", "today").map_or_else(
+			|_| {
+				let seconds = SystemTime::now()
+					.duration_since(UNIX_EPOCH)
+					.map_or(0, |elapsed| elapsed.as_secs());
+				Self::at(Time::new(i64::try_from(seconds).unwrap_or_default(), 0))
+			},
+			|now| Self::at(now.when()),
+		)
+	}
+
+	/// The day of the given time in its own time zone
+	pub fn at(time: Time) -> Self {
+		let local = time.seconds() + i64::from(time.offset_minutes()) * 60;
+		Self {
+			days: local.div_euclid(DAY_IN_SECONDS),
+		}
+	}
+
+	/// Parses `YYYY-MM-DD`
+	pub fn parse(date: &str) -> Option<Self> {
+		let mut parts = date.splitn(3, '-').map(str::parse::<i64>);
+		let (year, month, day) = (parts.next()?.ok()?, parts.next()?.ok()?, parts.next()?.ok()?);
+		if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+			return None;
+		}
+
+		let parsed = Self {
+			days: days_from_civil(year, month, day),
+		};
+		// Catch days out of their month (e.g. 02-30), which would silently roll over
+		(parsed.civil() == (year, month, day)).then_some(parsed)
+	}
+
+	/// Monday is 0
+	pub fn weekday(self) -> u8 {
+		// 1970-01-01 was a Thursday
+		u8::try_from((self.days + 3).rem_euclid(7)).unwrap_or_default()
+	}
+
+	// http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+	fn civil(self) -> (i64, i64, i64) {
+		let z = self.days + 719_468;
+		let era = z.div_euclid(146_097);
+		let doe = z.rem_euclid(146_097);
+		let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+		let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+		let mp = (5 * doy + 2) / 153;
+		let day = doy - (153 * mp + 2) / 5 + 1;
+		let month = if mp < 10 { mp + 3 } else { mp - 9 };
+		let year = yoe + era * 400 + i64::from(month <= 2);
+		(year, month, day)
+	}
+}
+
+impl Display for Date {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let (year, month, day) = self.civil();
+		write!(f, "{year:04}-{month:02}-{day:02}")
+	}
+}
+
+// http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+	let year = if month <= 2 { year - 1 } else { year };
+	let era = year.div_euclid(400);
+	let yoe = year.rem_euclid(400);
+	let mp = if month > 2 { month - 3 } else { month + 9 };
+	let doy = (153 * mp + 2) / 5 + day - 1;
+	let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+	era * 146_097 + doe - 719_468
+}
@@ -0,0 +1,29 @@
+use super::calendar::Date;
+use super::partners::Rota;
+use super::schedule::Schedule;
+use crate::common::fs::file_reader::{FileReader, Reader};
+use crate::common::{conf, env};
+use git2::Repository;
+use std::path::PathBuf;
+
+// No rota file (or no git identity) simply means there is nothing to pre-select
+pub fn init() -> Option<Rota> {
+	let cwd = env::cwd().ok()?;
+	let user_email = Repository::discover(&cwd)
+		.ok()?
+		.config()
+		.ok()?
+		.get_string("user.email")
+		.ok()?;
+
+	let rota_file = conf::rota_file();
+	let lines = FileReader
+		.read_non_empty_lines(&cwd.join(&rota_file))
+		.or_else(|_| {
+			let config_dir = env::xdg_config().or_else(|_| env::home().map(|home| format!("{home}/.config")))?;
+			FileReader.read_non_empty_lines(&PathBuf::from(config_dir).join(conf::authors_dir()).join(&rota_file))
+		})
+		.ok()?;
+
+	Some(Rota::new(Schedule::from(&lines), Date::today(), &user_email))
+}
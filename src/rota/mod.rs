@@ -0,0 +1,11 @@
+pub mod calendar;
+pub mod di;
+pub mod partners;
+pub mod schedule;
+
+#[cfg(test)]
+mod test {
+	mod calendar_should;
+	mod partners_should;
+	mod schedule_should;
+}
@@ -0,0 +1,54 @@
+use super::calendar::Date;
+use super::schedule::Schedule;
+use crate::authors::author::Author;
+
+pub struct Rota {
+	schedule: Schedule,
+	today: Date,
+	user_email: String,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Partners {
+	pub aliases: Vec<String>,
+	pub unknown: Vec<String>,
+}
+
+impl Rota {
+	pub fn new(schedule: Schedule, today: Date, user_email: &str) -> Self {
+		Self {
+			schedule,
+			today,
+			user_email: user_email.to_string(),
+		}
+	}
+
+	/// Today's active partners of the current user, along with any alias in the rota missing from the authors
+	pub fn partners(&self, authors: &[Author]) -> Partners {
+		let find = |alias: &String| authors.iter().find(|author| author.alias() == *alias);
+		let unknown = self
+			.schedule
+			.aliases()
+			.into_iter()
+			.filter(|alias| find(alias).is_none())
+			.collect();
+
+		let Some(me) = authors
+			.iter()
+			.find(|author| author.email().eq_ignore_ascii_case(&self.user_email))
+		else {
+			return Partners {
+				aliases: Vec::new(),
+				unknown,
+			};
+		};
+
+		let aliases = self
+			.schedule
+			.partners_of(&me.alias(), self.today)
+			.into_iter()
+			.filter(|alias| find(alias).is_some_and(Author::is_active))
+			.collect();
+		Partners { aliases, unknown }
+	}
+}
@@ -0,0 +1,98 @@
+use super::calendar::Date;
+
+const WEEKDAYS: [&str; 7] = [
+	"monday",
+	"tuesday",
+	"wednesday",
+	"thursday",
+	"friday",
+	"saturday",
+	"sunday",
+];
+
+enum Day {
+	Weekday(u8),
+	Date(Date),
+}
+
+struct Rule {
+	days: Vec<Day>,
+	groups: Vec<Vec<String>>,
+}
+
+pub struct Schedule {
+	rules: Vec<Rule>,
+}
+
+impl Schedule {
+	/// One rule per line, such as `mon,wed: a+b c+d` or `2024-12-24: a+e`
+	pub fn from(lines: &[String]) -> Self {
+		let rules = lines
+			.iter()
+			.map(|line| line.trim())
+			.filter(|line| !line.starts_with('#'))
+			.filter_map(|line| line.split_once(':'))
+			.map(|(days, groups)| Rule {
+				days: tokens(days).filter_map(to_day).collect(),
+				groups: tokens(groups)
+					.map(|group| group.split('+').map(String::from).collect())
+					.collect(),
+			})
+			.collect();
+		Self { rules }
+	}
+
+	/// Date rules take precedence over weekday rules
+	pub fn groups_on(&self, date: Date) -> Vec<Vec<String>> {
+		let on_date = self.groups_matching(|day| matches!(day, Day::Date(d) if *d == date));
+		if on_date.is_empty() {
+			self.groups_matching(|day| matches!(day, Day::Weekday(w) if *w == date.weekday()))
+		} else {
+			on_date
+		}
+	}
+
+	pub fn partners_of(&self, alias: &str, date: Date) -> Vec<String> {
+		self.groups_on(date)
+			.into_iter()
+			.filter(|group| group.iter().any(|member| member == alias))
+			.flatten()
+			.filter(|member| member != alias)
+			.collect()
+	}
+
+	pub fn aliases(&self) -> Vec<String> {
+		let mut aliases: Vec<String> = Vec::new();
+		for alias in self.rules.iter().flat_map(|rule| rule.groups.iter().flatten()) {
+			if !aliases.contains(alias) {
+				aliases.push(alias.clone());
+			}
+		}
+		aliases
+	}
+
+	fn groups_matching(&self, matches: impl Fn(&Day) -> bool) -> Vec<Vec<String>> {
+		self.rules
+			.iter()
+			.filter(|rule| rule.days.iter().any(&matches))
+			.flat_map(|rule| rule.groups.clone())
+			.collect()
+	}
+}
+
+fn tokens(field: &str) -> impl Iterator<Item = &str> {
+	field
+		.split(|c: char| c == ',' || c.is_whitespace())
+		.filter(|token| !token.is_empty())
+}
+
+fn to_day(token: &str) -> Option<Day> {
+	let token = token.to_lowercase();
+	let weekday = WEEKDAYS
+		.iter()
+		.position(|weekday| token.len() >= 3 && weekday.starts_with(&token));
+	match weekday {
+		Some(weekday) => u8::try_from(weekday).ok().map(Day::Weekday),
+		None => Date::parse(&token).map(Day::Date),
+	}
+}
@@ -0,0 +1,44 @@
+use crate::rota::calendar::Date;
+use git2::Time;
+use parameterized::parameterized;
+
+#[parameterized(date = {
+	"1970-01-01", "2000-02-29", "2024-10-21", "2026-10-19", "2026-12-27"
+}, weekday = {
+	3, 1, 0, 0, 6
+})]
+fn know_the_weekday_of_a_date(date: &str, weekday: u8) {
+	let date = Date::parse(date).expect("a valid date");
+
+	assert_eq!(date.weekday(), weekday);
+}
+
+#[parameterized(date = {
+	"2026-02-30", "2025-02-29", "2026-13-01", "2026-00-10", "not a date", "2026-10"
+})]
+fn not_parse_invalid_dates(date: &str) {
+	assert!(Date::parse(date).is_none());
+}
+
+#[test]
+fn tell_apart_different_dates() {
+	assert_ne!(Date::parse("2026-10-19"), Date::parse("2026-10-20"));
+	assert_eq!(Date::parse("2026-10-19"), Date::parse("2026-10-19"));
+}
+
+#[parameterized(date = { "2026-10-19", "1999-01-01", "2024-02-29" })]
+fn display_as_parsed(date: &str) {
+	let parsed = Date::parse(date).expect("a valid date");
+
+	assert_eq!(parsed.to_string(), date);
+}
+
+// 2026-10-19 23:30 UTC
+#[parameterized(offset_minutes = { 0, -120, 60, 840 }, date = {
+	"2026-10-19", "2026-10-19", "2026-10-20", "2026-10-20"
+})]
+fn take_the_day_in_the_time_zone_of_the_time(offset_minutes: i32, date: &str) {
+	let time = Time::new(1_792_452_600, offset_minutes);
+
+	assert_eq!(Date::at(time).to_string(), date);
+}
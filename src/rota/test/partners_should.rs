@@ -0,0 +1,61 @@
+use crate::authors::author::Author;
+use crate::rota::calendar::Date;
+use crate::rota::partners::{Partners, Rota};
+use crate::rota::schedule::Schedule;
+
+// 2026-10-19 is a Monday
+fn rota_for(user_email: &str, lines: &[&str]) -> Rota {
+	Rota::new(
+		Schedule::from(&lines.iter().map(ToString::to_string).collect::<Vec<_>>()),
+		Date::parse("2026-10-19").expect("a valid date"),
+		user_email,
+	)
+}
+
+fn authors() -> Vec<Author> {
+	vec![
+		Author::from("a", "alice", "alice@wonderland.not"),
+		Author::from("b", "bob", "bob@builder.not"),
+		Author::from("e", "erica", "erica@x.not").deactivated(),
+	]
+}
+
+#[test]
+fn give_todays_partners_of_the_current_user() {
+	let rota = rota_for("Alice@Wonderland.not", &["mon: a+b", "tue: a+e"]);
+
+	assert_eq!(
+		rota.partners(&authors()),
+		Partners {
+			aliases: vec!["b".to_string()],
+			unknown: vec![],
+		}
+	);
+}
+
+#[test]
+fn skip_inactive_partners() {
+	let rota = rota_for("alice@wonderland.not", &["mon: a+b+e"]);
+
+	assert_eq!(rota.partners(&authors()).aliases, vec!["b"]);
+}
+
+#[test]
+fn report_unknown_aliases() {
+	let rota = rota_for("alice@wonderland.not", &["mon: a+z", "tue: y+b"]);
+
+	assert_eq!(
+		rota.partners(&authors()),
+		Partners {
+			aliases: vec![],
+			unknown: vec!["z".to_string(), "y".to_string()],
+		}
+	);
+}
+
+#[test]
+fn not_preselect_anyone_for_a_user_missing_from_the_authors() {
+	let rota = rota_for("someone@else.not", &["mon: a+b"]);
+
+	assert!(rota.partners(&authors()).aliases.is_empty());
+}
@@ -0,0 +1,61 @@
+use crate::rota::calendar::Date;
+use crate::rota::schedule::Schedule;
+
+// 2026-10-19 is a Monday
+fn on(date: &str) -> Date {
+	Date::parse(date).expect("a valid date")
+}
+
+fn schedule(lines: &[&str]) -> Schedule {
+	Schedule::from(&lines.iter().map(ToString::to_string).collect::<Vec<_>>())
+}
+
+#[test]
+fn pick_the_groups_for_the_weekday() {
+	let schedule = schedule(&["mon,wed: a+b c+d", "tue thu: a+e"]);
+
+	assert_eq!(
+		schedule.groups_on(on("2026-10-21")),
+		vec![vec!["a", "b"], vec!["c", "d"]]
+	);
+	assert_eq!(schedule.groups_on(on("2026-10-22")), vec![vec!["a", "e"]]);
+	assert!(schedule.groups_on(on("2026-10-23")).is_empty());
+}
+
+#[test]
+fn accept_full_and_capitalized_weekday_names() {
+	let schedule = schedule(&["Monday, TUE: a+b"]);
+
+	assert_eq!(schedule.groups_on(on("2026-10-19")), vec![vec!["a", "b"]]);
+	assert_eq!(schedule.groups_on(on("2026-10-20")), vec![vec!["a", "b"]]);
+}
+
+#[test]
+fn prefer_date_rules_over_weekday_rules() {
+	let schedule = schedule(&["mon: a+b", "2026-10-19: a+c"]);
+
+	assert_eq!(schedule.groups_on(on("2026-10-19")), vec![vec!["a", "c"]]);
+	assert_eq!(schedule.groups_on(on("2026-10-26")), vec![vec!["a", "b"]]);
+}
+
+#[test]
+fn ignore_comments_and_invalid_lines() {
+	let schedule = schedule(&["# mon: x+y", "mon a+b", "someday: a+z", "mon: a+c"]);
+
+	assert_eq!(schedule.groups_on(on("2026-10-19")), vec![vec!["a", "c"]]);
+}
+
+#[test]
+fn give_the_partners_of_an_alias() {
+	let schedule = schedule(&["mon: a+b+e c+d"]);
+
+	assert_eq!(schedule.partners_of("b", on("2026-10-19")), vec!["a", "e"]);
+	assert!(schedule.partners_of("x", on("2026-10-19")).is_empty());
+}
+
+#[test]
+fn list_every_alias_once() {
+	let schedule = schedule(&["mon: a+b", "tue: a+c"]);
+
+	assert_eq!(schedule.aliases(), vec!["a", "b", "c"]);
+}
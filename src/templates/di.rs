@@ -0,0 +1,31 @@
+use super::library::Templates;
+use super::variables::Context;
+use crate::common::fs::file_reader::FileReader;
+use crate::common::{conf, env};
+use crate::git::ticket::TicketPattern;
+use crate::rota::calendar::Date;
+use crate::Result;
+use git2::Repository;
+use std::path::PathBuf;
+
+pub fn init() -> Result<Templates> {
+	let config_dir = env::xdg_config().or_else(|_| env::home().map(|home| format!("{home}/.config")))?;
+	let dir = PathBuf::from(config_dir)
+		.join(conf::authors_dir())
+		.join(conf::templates_dir());
+	let branch = current_branch();
+	let context = Context::new(
+		&branch,
+		TicketPattern::configured()?.find_in_branch(&branch),
+		&Date::today().to_string(),
+	);
+	Templates::load(&FileReader, &dir, context)
+}
+
+fn current_branch() -> String {
+	env::cwd()
+		.ok()
+		.and_then(|cwd| Repository::discover(cwd).ok())
+		.and_then(|repo| repo.head().ok()?.shorthand().map(String::from))
+		.unwrap_or_default()
+}
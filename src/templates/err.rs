@@ -0,0 +1,60 @@
+use crate::error::Error;
+use std::{any::Any, fmt::Display};
+
+#[derive(Debug)]
+pub enum TemplatesError {
+	NotFound(String),
+	Unknown(String),
+	NoneSelected,
+}
+
+impl Error for TemplatesError {
+	fn as_any(&self) -> &dyn Any {
+		self
+	}
+}
+
+impl std::error::Error for TemplatesError {}
+
+impl PartialEq for TemplatesError {
+	fn eq(&self, other: &Self) -> bool {
+		matches!(
+			(self, other),
+			(TemplatesError::NotFound(_), TemplatesError::NotFound(_))
+				| (TemplatesError::Unknown(_), TemplatesError::Unknown(_))
+				| (TemplatesError::NoneSelected, TemplatesError::NoneSelected)
+		)
+	}
+}
+
+impl Display for TemplatesError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "Templates: ")?;
+		match self {
+			TemplatesError::NotFound(location) => write!(f, "No templates at {location}"),
+			TemplatesError::Unknown(name) => write!(f, "No template named {name}"),
+			TemplatesError::NoneSelected => write!(f, "No template selected"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_templates_error_display() {
+		assert_eq!(
+			format!("{}", TemplatesError::NotFound("path/to/dir".to_string())),
+			"Templates: No templates at path/to/dir"
+		);
+		assert_eq!(
+			format!("{}", TemplatesError::Unknown("hotfix".to_string())),
+			"Templates: No template named hotfix"
+		);
+		assert_eq!(
+			format!("{}", TemplatesError::NoneSelected),
+			"Templates: No template selected"
+		);
+	}
+}
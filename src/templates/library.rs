@@ -0,0 +1,62 @@
+use super::err::TemplatesError;
+use super::variables::{self, Context};
+use crate::common::fs::file_reader::Reader;
+use crate::Result;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Named message templates, one file per template
+pub struct Templates {
+	templates: Vec<(String, String)>,
+	context: Context,
+}
+
+impl Templates {
+	pub fn new(mut templates: Vec<(String, String)>, context: Context) -> Self {
+		templates.sort();
+		Self { templates, context }
+	}
+
+	/// Every file of the directory is a template named after it, unreadable ones left out
+	pub fn load(reader: &dyn Reader, dir: &Path, context: Context) -> Result<Self> {
+		let not_found = || TemplatesError::NotFound(dir.to_string_lossy().to_string());
+		let mut templates = Vec::new();
+		for path in reader.list_files(dir).map_err(|_| not_found())? {
+			let (Some(name), Ok(template)) = (path.file_name(), reader.read(&path)) else {
+				continue;
+			};
+			templates.push((name.to_string_lossy().to_string(), template));
+		}
+		if templates.is_empty() {
+			return Err(not_found().into());
+		}
+		Ok(Self::new(templates, context))
+	}
+
+	pub fn names(&self) -> Vec<String> {
+		self.templates.iter().map(|(name, _)| name.clone()).collect()
+	}
+
+	/// Variables out of the context are asked for once, however many times they show up
+	pub fn render(&self, name: &str, mut ask: impl FnMut(&str) -> Result<String>) -> Result<String> {
+		let template = self
+			.templates
+			.iter()
+			.find(|(template_name, _)| template_name == name)
+			.map(|(_, template)| template)
+			.ok_or_else(|| TemplatesError::Unknown(name.to_string()))?;
+
+		let mut answers: HashMap<String, String> = HashMap::new();
+		variables::render(template, |variable| {
+			if let Some(value) = self.context.get(variable) {
+				return Ok(value);
+			}
+			if let Some(answer) = answers.get(variable) {
+				return Ok(answer.clone());
+			}
+			let answer = ask(variable)?;
+			answers.insert(variable.to_string(), answer.clone());
+			Ok(answer)
+		})
+	}
+}
@@ -0,0 +1,10 @@
+pub mod di;
+pub mod err;
+pub mod library;
+pub mod variables;
+
+#[cfg(test)]
+mod test {
+	mod library_should;
+	mod variables_should;
+}
@@ -0,0 +1,92 @@
+use crate::common::fs::file_reader::MockReader;
+use crate::error::assert_error_type;
+use crate::templates::err::TemplatesError;
+use crate::templates::library::Templates;
+use crate::templates::variables::Context;
+use crate::Result;
+use std::path::{Path, PathBuf};
+
+fn templates() -> Templates {
+	Templates::new(
+		vec![
+			("release".to_string(), "release: {date}".to_string()),
+			("hotfix".to_string(), "fix({scope}): {ticket} in {scope}".to_string()),
+		],
+		Context::new("hotfix/ABC-9", Some("ABC-9".to_string()), "2026-10-19"),
+	)
+}
+
+#[test]
+fn list_names_in_order() {
+	assert_eq!(templates().names(), vec!["hotfix", "release"]);
+}
+
+#[test]
+fn render_known_variables_without_asking() -> Result<()> {
+	let rendered = templates().render("release", |_| Err("should not ask".into()))?;
+
+	assert_eq!(rendered, "release: 2026-10-19");
+	Ok(())
+}
+
+#[test]
+fn ask_once_for_each_unknown_variable() -> Result<()> {
+	let mut asked = Vec::new();
+
+	let rendered = templates().render("hotfix", |variable| {
+		asked.push(variable.to_string());
+		Ok("api".to_string())
+	})?;
+
+	assert_eq!(rendered, "fix(api): ABC-9 in api");
+	assert_eq!(asked, vec!["scope"]);
+	Ok(())
+}
+
+#[test]
+fn fail_for_an_unknown_template() {
+	let result = templates().render("feature", |_| Ok(String::new()));
+
+	assert_error_type(&result, &TemplatesError::Unknown(String::new()));
+}
+
+fn context() -> Context {
+	Context::new("main", None, "2026-10-19")
+}
+
+#[test]
+fn load_a_template_per_file() -> Result<()> {
+	let mut reader = MockReader::new();
+	reader
+		.expect_list_files()
+		.returning(|dir| Ok(vec![dir.join("release"), dir.join("unreadable"), dir.join("hotfix")]));
+	reader.expect_read().returning(|path| match path.file_name() {
+		Some(name) if name == "unreadable" => Err("unreadable".into()),
+		_ => Ok("a template".to_string()),
+	});
+
+	let templates = Templates::load(&reader, Path::new("/templates"), context())?;
+
+	assert_eq!(templates.names(), vec!["hotfix", "release"]);
+	Ok(())
+}
+
+#[test]
+fn not_load_from_a_missing_dir() {
+	let mut reader = MockReader::new();
+	reader.expect_list_files().returning(|_| Err("no such dir".into()));
+
+	let result = Templates::load(&reader, Path::new("/templates"), context());
+
+	assert_error_type(&result, &TemplatesError::NotFound(String::new()));
+}
+
+#[test]
+fn not_load_from_an_empty_dir() {
+	let mut reader = MockReader::new();
+	reader.expect_list_files().returning(|_| Ok(Vec::<PathBuf>::new()));
+
+	let result = Templates::load(&reader, Path::new("/templates"), context());
+
+	assert_error_type(&result, &TemplatesError::NotFound(String::new()));
+}
@@ -0,0 +1,37 @@
+use crate::templates::variables::{render, Context};
+use crate::Result;
+use parameterized::parameterized;
+
+#[test]
+fn know_only_branch_ticket_and_date() {
+	let context = Context::new("feature/ABC-1", Some("ABC-1".to_string()), "2026-10-19");
+
+	assert_eq!(context.get("branch"), Some("feature/ABC-1".to_string()));
+	assert_eq!(context.get("ticket"), Some("ABC-1".to_string()));
+	assert_eq!(context.get("date"), Some("2026-10-19".to_string()));
+	assert_eq!(context.get("scope"), None);
+}
+
+#[test]
+fn replace_every_variable() -> Result<()> {
+	let rendered = render("fix({scope}): {what} on {date}", |variable| Ok(variable.to_uppercase()))?;
+
+	assert_eq!(rendered, "fix(SCOPE): WHAT on DATE");
+	Ok(())
+}
+
+#[parameterized(template = {
+	"{}", "{ not a variable }", "json: {\"a\": 1}", "{unclosed", "closed}", "{Upper}"
+})]
+fn leave_other_braces_alone(template: &str) {
+	let rendered = render(template, |_| Ok("X".to_string()));
+
+	assert!(matches!(rendered, Ok(text) if text == template));
+}
+
+#[test]
+fn fail_when_a_value_is_missing() {
+	let rendered = render("{scope}", |_| Err("no scope".into()));
+
+	assert!(rendered.is_err());
+}
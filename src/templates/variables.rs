@@ -0,0 +1,54 @@
+use crate::Result;
+
+/// Values known without asking, the others (e.g. `{scope}`) are prompted for
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Context {
+	pub branch: String,
+	pub ticket: String,
+	pub date: String,
+}
+
+impl Context {
+	pub fn new(branch: &str, ticket: Option<String>, date: &str) -> Self {
+		Self {
+			branch: branch.to_string(),
+			ticket: ticket.unwrap_or_default(),
+			date: date.to_string(),
+		}
+	}
+
+	pub fn get(&self, variable: &str) -> Option<String> {
+		match variable {
+			"branch" => Some(self.branch.clone()),
+			"ticket" => Some(self.ticket.clone()),
+			"date" => Some(self.date.clone()),
+			_ => None,
+		}
+	}
+}
+
+/// Replaces every `{variable}` (lowercase letters and underscores), leaving other braces alone
+pub fn render(template: &str, mut value_of: impl FnMut(&str) -> Result<String>) -> Result<String> {
+	let mut rendered = String::new();
+	let mut rest = template;
+	while let Some(open) = rest.find('{') {
+		rendered.push_str(&rest[..open]);
+		let after = &rest[open + 1..];
+		match after.find('}') {
+			Some(close) if is_variable(&after[..close]) => {
+				rendered.push_str(&value_of(&after[..close])?);
+				rest = &after[close + 1..];
+			}
+			_ => {
+				rendered.push('{');
+				rest = after;
+			}
+		}
+	}
+	rendered.push_str(rest);
+	Ok(rendered)
+}
+
+fn is_variable(name: &str) -> bool {
+	!name.is_empty() && name.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+}
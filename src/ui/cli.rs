@@ -2,13 +2,27 @@ use super::err::UiError;
 use super::input_reader::InputReader;
 use crate::authors::author::Author;
 use crate::common::runner::Runner;
+use crate::git::conventional::{Conventional, Subject};
+use crate::git::identity::Identity;
+use crate::git::preview::Preview;
 use crate::Result;
 use colored::Colorize;
 use std::collections::hash_map::DefaultHasher;
 use std::hash::{Hash, Hasher};
 use std::io::Write;
+use std::thread;
+use std::time::Duration;
 
 const FZF_SEPARATOR: &str = " - ";
+const PARAGRAPH_BREAK: &str = ".";
+
+#[derive(Debug, PartialEq)]
+pub enum Decision {
+	Commit,
+	EditMessage,
+	EditCoAuthors,
+	Abort,
+}
 
 pub struct Cli {
 	reader: Box<dyn InputReader>,
@@ -20,49 +34,207 @@ impl Cli {
 		Self { reader, runner }
 	}
 
-	pub fn message_prompt(&mut self) -> Result<String> {
-		let prompt_msg = "Enter commit message:";
-		let input = self.reader.readline(&format!("{prompt_msg}\n"))?;
-		Ok(input.trim().to_string())
+	/// The subject, then the body line by line until an empty line or Ctrl-D.
+	/// A line holding only `.` stands for the blank line between paragraphs
+	pub fn message_prompt(&mut self, initial: Option<&str>) -> Result<String> {
+		let (subject, body) = initial.map_or(("", ""), |initial| {
+			initial.trim().split_once('\n').unwrap_or((initial.trim(), ""))
+		});
+		let subject = self.reader.readline_subject("Enter commit message:\n", (subject, ""))?;
+
+		// Pre-filled a line at a time, blank lines included
+		let mut initial_lines =
+			body.trim_matches('\n')
+				.lines()
+				.map(|line| if line.trim().is_empty() { PARAGRAPH_BREAK } else { line });
+		let mut lines = Vec::new();
+		loop {
+			let prompt_msg = match (lines.is_empty(), body.trim().is_empty()) {
+				(false, _) => "",
+				(true, true) => "Enter the body, a single . between paragraphs, end with an empty line or Ctrl-D:\n",
+				(true, false) => {
+					"Enter the body, a single . between paragraphs, empty a line to remove it, end with Ctrl-D:\n"
+				}
+			};
+			let initial = initial_lines.next();
+			match self
+				.reader
+				.readline_until_end(prompt_msg, (initial.unwrap_or_default(), ""))?
+			{
+				Some(line) if line.trim() == PARAGRAPH_BREAK => lines.push(String::new()),
+				Some(line) if !line.trim().is_empty() => lines.push(line.trim_end().to_string()),
+				// Emptying a pre-filled line removes it, the lines after it still to come
+				Some(_) if initial.is_some() => {}
+				Some(_) => break,
+				// Ending early keeps the pre-filled lines not read yet
+				None => {
+					lines.extend(initial_lines.map(|line| {
+						if line == PARAGRAPH_BREAK {
+							String::new()
+						} else {
+							line.to_string()
+						}
+					}));
+					break;
+				}
+			}
+		}
+
+		let body = lines.join("\n");
+		let body = body.trim_matches('\n');
+		if body.is_empty() {
+			Ok(subject.trim().to_string())
+		} else {
+			Ok(format!("{}\n\n{body}", subject.trim()))
+		}
 	}
 
-	pub fn aliases_prompt(&mut self, authors: &[Author]) -> Result<Vec<String>> {
-		let pretty_authors = Self::prettify_authors(authors);
-		let prompt_msg = "Enter co-authors aliases separated by spaces:";
-		let input = self.reader.readline(&format!("\n{pretty_authors}\n\n{prompt_msg}\n"))?;
-		Ok(input.split_whitespace().map(ToString::to_string).collect())
+	/// Builds a Conventional Commit message step by step, asking again for unknown types and empty descriptions
+	pub fn conventional_prompt(&mut self, conventional: &Conventional, scope: Option<&str>) -> Result<String> {
+		let types = conventional.types().join(" ");
+		let kind = loop {
+			let kind = self.reader.readline(&format!("Enter type ({types}):\n"))?;
+			if conventional.is_known(kind.trim()) {
+				break kind;
+			}
+			Self::warn(&format!("Unknown type {}", kind.trim()));
+		};
+
+		let scope_msg = "Enter scope (optional):\n";
+		let scope = match scope {
+			Some(scope) => self.reader.readline_with_prompt(scope_msg, (scope, ""))?,
+			None => self.reader.readline(scope_msg)?,
+		};
+		let breaking = self
+			.reader
+			.readline("Breaking change? [y/N]:\n")?
+			.trim()
+			.eq_ignore_ascii_case("y");
+		let description = loop {
+			let description = self.reader.readline("Enter description:\n")?;
+			if !description.trim().is_empty() {
+				break description;
+			}
+		};
+		let breaking_change = if breaking {
+			Some(self.reader.readline("Describe the breaking change (optional):\n")?)
+		} else {
+			None
+		};
+
+		Ok(Subject::new(&kind, Some(&scope), breaking, &description).message(breaking_change.as_deref()))
 	}
 
-	pub fn pre_populated_message_prompt(&mut self, prev_commit_msg: &str) -> Result<String> {
-		let prompt_msg = "Update commit message:";
+	/// Yes unless answered otherwise
+	pub fn confirm(&mut self, question: &str) -> Result<bool> {
+		let input = self.reader.readline(&format!("{question} [Y/n]\n"))?;
+		Ok(!input.trim().to_lowercase().starts_with('n'))
+	}
+
+	/// Shows what is about to be committed and asks what to do, again until answered
+	pub fn preview_prompt(&mut self, preview: &Preview) -> Result<Decision> {
+		println!("{}", Self::prettify_preview(preview));
+		loop {
+			let input = self
+				.reader
+				.readline("Commit? [Y]es, edit [m]essage, edit [c]o-authors, [a]bort\n")?;
+			match input.trim().to_lowercase().as_str() {
+				"" | "y" | "yes" => return Ok(Decision::Commit),
+				"m" | "message" => return Ok(Decision::EditMessage),
+				"c" | "co-authors" => return Ok(Decision::EditCoAuthors),
+				"a" | "abort" | "n" | "no" => return Ok(Decision::Abort),
+				other => Self::warn(&format!("Unknown answer {other}")),
+			}
+		}
+	}
+
+	pub fn aliases_prompt(&mut self, authors: &[Author], preselected: &[String]) -> Result<Vec<String>> {
+		let pretty_authors = Self::prettify_authors(authors);
+		let prompt_msg = format!("\n{pretty_authors}\n\nEnter co-authors aliases separated by spaces:\n");
+		let mut aliases: Vec<String> = authors.iter().map(Author::alias).collect();
+		aliases.sort();
+		aliases.dedup();
 		let input = self
 			.reader
-			.readline_with_prompt(&format!("{prompt_msg}\n"), (prev_commit_msg, ""))?;
-		Ok(input.trim().to_string())
+			.readline_aliases(&prompt_msg, (&preselected.join(" "), ""), &aliases)?;
+		Ok(input.split_whitespace().map(ToString::to_string).collect())
 	}
 
 	pub fn fzf_prompt(&self, authors: &[Author]) -> Result<Vec<u64>> {
-		let mut fzf_proc = self
-			.runner
-			.attach("fzf", &["--multi".to_string(), "--ansi".to_string()])?;
+		let lines: Vec<String> = authors.iter().map(Self::fzf_format).collect();
+		let output = self.fzf(&["--multi".to_string(), "--ansi".to_string()], &lines)?;
+		let selected_aliases: Vec<u64> = output
+			.lines()
+			.map(|line| Self::hash_of(&line.replace(FZF_SEPARATOR, "")))
+			.collect();
+
+		Ok(selected_aliases)
+	}
+
+	pub fn template_prompt(&self, names: &[String]) -> Result<Option<String>> {
+		let output = self.fzf(&[], names)?;
+		Ok(output.lines().next().map(ToString::to_string))
+	}
+
+	pub fn variable_prompt(&mut self, variable: &str) -> Result<String> {
+		let input = self.reader.readline(&format!("Enter {variable}:\n"))?;
+		Ok(input.trim().to_string())
+	}
+
+	pub fn show_authors(heading: &str, authors: &[Author]) {
+		println!("{heading}\n{}", Self::prettify_authors(authors));
+	}
+
+	pub fn show(report: &str) {
+		println!("{report}");
+	}
+
+	pub fn warn(warning: &str) {
+		eprintln!("{} {warning}", "[Warning]".yellow());
+	}
+
+	pub fn countdown(duration: Duration, driver: &Author) {
+		for remaining in (1..=duration.as_secs()).rev() {
+			print!(
+				"\r{} {:02}:{:02} left for {}",
+				"⦔".yellow(),
+				remaining / 60,
+				remaining % 60,
+				Self::prettify_driver(driver)
+			);
+			std::io::stdout().flush().ok();
+			thread::sleep(Duration::from_secs(1));
+		}
+		println!();
+	}
+
+	pub fn announce_driver(driver: &Author, bell: bool) {
+		if bell {
+			print!("\x07");
+		}
+		println!("Time is up! Next driver: {}", Self::prettify_driver(driver));
+	}
+
+	/// The command may come with its own args, as in `notify-send -u critical`, the message going last
+	pub fn notify(&self, cmd: &str, message: &str) -> Result<()> {
+		self.runner.run_script(&format!("{cmd} \"$1\""), &[message.to_string()])
+	}
+
+	fn fzf(&self, args: &[String], lines: &[String]) -> Result<String> {
+		let mut fzf_proc = self.runner.attach("fzf", args)?;
 		let stdin = fzf_proc
 			.stdin
 			.as_mut()
 			.ok_or(UiError::Fzf("Could not attach stdin".to_string()))?;
 
-		for author in authors.iter().map(Self::fzf_format) {
-			writeln!(stdin, "{author}").map_err(|_| UiError::Fzf("Could not pipe to stdin".to_string()))?;
+		for line in lines {
+			writeln!(stdin, "{line}").map_err(|_| UiError::Fzf("Could not pipe to stdin".to_string()))?;
 		}
 
 		let output = fzf_proc
 			.wait_with_output()
 			.map_err(|_| UiError::Fzf("Could not read output".to_string()))?;
-		let selected_aliases: Vec<u64> = String::from_utf8_lossy(&output.stdout)
-			.lines()
-			.map(|line| Self::hash_of(&line.replace(FZF_SEPARATOR, "")))
-			.collect();
-
-		Ok(selected_aliases)
+		Ok(String::from_utf8_lossy(&output.stdout).to_string())
 	}
 
 	fn hash_of(str: &str) -> u64 {
@@ -71,6 +243,24 @@ impl Cli {
 		hasher.finish()
 	}
 
+	fn prettify_preview(preview: &Preview) -> String {
+		let identity = |identity: &Identity| format!("{} <{}>", identity.name(), identity.email());
+		let heading = if preview.amend { "Amending" } else { "Committing" };
+		let staged: Vec<String> = preview.staged.iter().map(|file| format!("  {file}")).collect();
+		format!(
+			"\n{} {}\n{} {}\n{} {}\n{}\n{}\n\n{}\n",
+			"⦔".yellow(),
+			heading.bold(),
+			"Author:   ".blue(),
+			identity(&preview.author),
+			"Committer:".blue(),
+			identity(&preview.committer),
+			"Staged:".blue(),
+			staged.join("\n"),
+			preview.message.formatted()
+		)
+	}
+
 	fn prettify_authors(authors: &[Author]) -> String {
 		authors.iter().map(Self::prettify).collect::<Vec<String>>().join("\n")
 	}
@@ -85,6 +275,10 @@ impl Cli {
 		)
 	}
 
+	fn prettify_driver(author: &Author) -> String {
+		format!("{} {} {}", author.alias().blue(), "->".green(), author.name())
+	}
+
 	fn fzf_format(author: &Author) -> String {
 		format!("{}{}{}", author.alias().blue(), FZF_SEPARATOR, author.name())
 	}
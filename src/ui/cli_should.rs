@@ -1,47 +1,221 @@
-use super::{cli::Cli, input_reader::MockInputReader};
-use crate::{authors::author::Author, common::runner::MockRunner};
+use super::{
+	cli::{Cli, Decision},
+	input_reader::MockInputReader,
+};
+use crate::{
+	authors::author::Author,
+	common::runner::MockRunner,
+	git::{conventional::Conventional, identity::Identity, preview::Preview, CommitMessage},
+};
+use parameterized::parameterized;
 
 #[test]
 fn prompt_for_message() {
 	let mut reader = MockInputReader::new();
 	reader
-		.expect_readline()
-		.withf(|prompt_msg| prompt_msg.contains("Enter commit message"))
+		.expect_readline_subject()
+		.withf(|prompt_msg, (left, _)| prompt_msg.contains("Enter commit message") && left.is_empty())
 		.times(1)
-		.returning(|_| Ok("whatever".to_string()));
+		.returning(|_, _| Ok("whatever".to_string()));
+	reader.expect_readline_until_end().returning(|_, _| Ok(None));
 	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
 
-	let _ = cli.message_prompt();
+	let _ = cli.message_prompt(None);
 	// Only interested in params passed to the mock (withf)
 }
 
+#[test]
+fn pre_fill_the_message_prompt_with_the_template() {
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_subject()
+		.withf(|prompt_msg, (left, _)| prompt_msg.contains("Enter commit message") && *left == "a template")
+		.times(1)
+		.returning(|_, _| Ok("whatever".to_string()));
+	reader
+		.expect_readline_until_end()
+		.withf(|prompt_msg, (left, _)| prompt_msg.contains("Enter the body") && *left == "a body")
+		.times(1)
+		.returning(|_, _| Ok(None));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let _ = cli.message_prompt(Some("a template\n\na body"));
+	// Only interested in params passed to the mock (withf)
+}
+
+#[test]
+fn pre_fill_the_body_a_line_at_a_time() {
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_subject()
+		.times(1)
+		.returning(|_, (subject, _)| Ok(subject.to_string()));
+	reader
+		.expect_readline_until_end()
+		.times(5)
+		.returning(|_, (line, _)| Ok((!line.is_empty()).then(|| line.to_string())));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.message_prompt(Some("a subject\n\nfirst line\nsecond line\n\nRefs: ABC-1"));
+
+	assert!(matches!(result, Ok(msg) if msg == "a subject\n\nfirst line\nsecond line\n\nRefs: ABC-1"));
+}
+
+#[test]
+fn remove_an_emptied_pre_filled_line_and_go_on() {
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_subject()
+		.times(1)
+		.returning(|_, (subject, _)| Ok(subject.to_string()));
+	reader.expect_readline_until_end().times(4).returning(|_, (line, _)| {
+		Ok((!line.is_empty()).then(|| if line == "first line" { "" } else { line }.to_string()))
+	});
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.message_prompt(Some("a subject\n\nfirst line\nsecond line\nthird line"));
+
+	assert!(matches!(result, Ok(msg) if msg == "a subject\n\nsecond line\nthird line"));
+}
+
+#[test]
+fn keep_the_pre_filled_lines_left_when_ended_early() {
+	let mut lines = vec![Some("first line".to_string()), None].into_iter();
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_subject()
+		.times(1)
+		.returning(|_, (subject, _)| Ok(subject.to_string()));
+	reader
+		.expect_readline_until_end()
+		.times(2)
+		.returning(move |_, _| Ok(lines.next().flatten()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.message_prompt(Some("a subject\n\nfirst line\nsecond line\n\nRefs: ABC-1"));
+
+	assert!(matches!(result, Ok(msg) if msg == "a subject\n\nfirst line\n\nRefs: ABC-1"));
+}
+
+#[test]
+fn separate_paragraphs_with_a_single_dot() {
+	let mut lines = vec!["first paragraph", " . ", "second paragraph", ".", ""].into_iter();
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_subject()
+		.times(1)
+		.returning(|_, _| Ok("a subject".to_string()));
+	reader
+		.expect_readline_until_end()
+		.times(5)
+		.returning(move |_, _| Ok(lines.next().map(ToString::to_string)));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.message_prompt(None);
+
+	assert!(matches!(result, Ok(msg) if msg == "a subject\n\nfirst paragraph\n\nsecond paragraph"));
+}
+
 #[test]
 fn trim_message() {
 	let trimmed_msg = "test commit message";
 	let padded_msg = format!(" {trimmed_msg}  ");
 	let mut reader = MockInputReader::new();
 	reader
-		.expect_readline()
+		.expect_readline_subject()
 		.times(1)
-		.returning(move |_| Ok(padded_msg.clone()));
+		.returning(move |_, _| Ok(padded_msg.clone()));
+	reader
+		.expect_readline_until_end()
+		.returning(|_, _| Ok(Some("  ".to_string())));
 	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
 
-	let result = cli.message_prompt();
+	let result = cli.message_prompt(None);
 
 	assert!(matches!(result, Ok(msg) if msg == trimmed_msg));
 }
 
+#[parameterized(end = { None, Some(String::new()) })]
+fn put_the_body_lines_below_the_subject_until_the_end(end: Option<String>) {
+	let mut lines = vec![Some("first line".to_string()), Some("second line  ".to_string()), end].into_iter();
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_subject()
+		.times(1)
+		.returning(|_, _| Ok("a subject".to_string()));
+	reader
+		.expect_readline_until_end()
+		.times(3)
+		.returning(move |_, _| Ok(lines.next().flatten()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.message_prompt(None);
+
+	assert!(matches!(result, Ok(msg) if msg == "a subject\n\nfirst line\nsecond line"));
+}
+
 #[test]
-fn prompt_for_aliases() {
+fn build_a_conventional_commit_message() {
 	let mut reader = MockInputReader::new();
+	let mut answers = vec!["feat", "y", "new api", "v1 is gone"].into_iter();
 	reader
 		.expect_readline()
-		.withf(|prompt_msg| prompt_msg.contains("Enter co-authors aliases"))
+		.times(4)
+		.returning(move |_| Ok(answers.next().unwrap_or_default().to_string()));
+	reader
+		.expect_readline_with_prompt()
+		.withf(|prompt_msg, (left, _)| prompt_msg.contains("Enter scope") && *left == "git")
 		.times(1)
-		.returning(|_| Ok("whatever".to_string()));
+		.returning(|_, _| Ok("git".to_string()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.conventional_prompt(&Conventional::new(vec!["feat".to_string()]), Some("git"));
+
+	assert!(matches!(result, Ok(msg) if msg == "feat(git)!: new api\n\nBREAKING CHANGE: v1 is gone"));
+}
+
+#[test]
+fn ask_again_for_an_unknown_conventional_type() {
+	let mut reader = MockInputReader::new();
+	let mut answers = vec!["wip", "fix", "", "n", "a bug"].into_iter();
+	reader
+		.expect_readline()
+		.times(5)
+		.returning(move |_| Ok(answers.next().unwrap_or_default().to_string()));
 	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
 
-	let _ = cli.aliases_prompt(&[]);
+	let result = cli.conventional_prompt(&Conventional::new(vec!["fix".to_string()]), None);
+
+	assert!(matches!(result, Ok(msg) if msg == "fix: a bug"));
+}
+
+#[parameterized(answer = { "", "y", "Yes", "n", "No" }, expected = { true, true, true, false, false })]
+fn confirm_unless_told_otherwise(answer: &str, expected: bool) {
+	let answer = answer.to_string();
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline()
+		.withf(|prompt_msg| prompt_msg.contains("Go on? [Y/n]"))
+		.times(1)
+		.returning(move |_| Ok(answer.clone()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.confirm("Go on?");
+
+	assert!(matches!(result, Ok(confirmed) if confirmed == expected));
+}
+
+#[test]
+fn prompt_for_aliases() {
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_aliases()
+		.withf(|prompt_msg, initial, _| prompt_msg.contains("Enter co-authors aliases") && initial == &("", ""))
+		.times(1)
+		.returning(|_, _, _| Ok("whatever".to_string()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let _ = cli.aliases_prompt(&[], &[]);
 	// Only interested in params passed to the mock (withf)
 }
 
@@ -50,12 +224,12 @@ fn space_split_aliases() {
 	let aliases = " a b cd   ";
 	let mut reader = MockInputReader::new();
 	reader
-		.expect_readline()
+		.expect_readline_aliases()
 		.times(1)
-		.returning(move |_| Ok(aliases.to_string()));
+		.returning(move |_, _, _| Ok(aliases.to_string()));
 	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
 
-	let result = cli.aliases_prompt(&[]);
+	let result = cli.aliases_prompt(&[], &[]);
 
 	assert!(matches!(result, Ok(aliases) if aliases == ["a", "b", "cd"]));
 }
@@ -67,13 +241,47 @@ fn pretty_print_authors_when_prompting_for_aliases() {
 	let author = Author::from(alias, name, "email");
 	let mut reader = MockInputReader::new();
 	reader
-		.expect_readline()
-		.withf(move |prompt_msg| contains_in_order(prompt_msg, &["⦔", alias, "->", name]))
+		.expect_readline_aliases()
+		.withf(move |prompt_msg, _, _| contains_in_order(prompt_msg, &["⦔", alias, "->", name]))
+		.times(1)
+		.returning(move |_, _, _| Ok("whatever".to_string()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let _ = cli.aliases_prompt(&[author], &[]);
+	// Only interested in params passed to the mock (withf)
+}
+
+#[test]
+fn pre_populate_aliases_prompt_with_preselected_aliases() {
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_aliases()
+		.withf(|prompt_msg, initial, _| prompt_msg.contains("Enter co-authors aliases") && initial == &("a b", ""))
+		.times(1)
+		.returning(|_, _, _| Ok("a b".to_string()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.aliases_prompt(&[], &["a".to_string(), "b".to_string()]);
+
+	assert!(matches!(result, Ok(aliases) if aliases == ["a", "b"]));
+}
+
+#[test]
+fn complete_each_alias_once_groups_included() {
+	let authors = [
+		Author::from("j", "John", "john@email"),
+		Author::from("a", "Alice", "alice@email"),
+		Author::from("j", "Jane", "jane@email"),
+	];
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline_aliases()
+		.withf(|_, _, aliases| aliases == ["a", "j"])
 		.times(1)
-		.returning(move |_| Ok("whatever".to_string()));
+		.returning(|_, _, _| Ok("j".to_string()));
 	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
 
-	let _ = cli.aliases_prompt(&[author]);
+	let _ = cli.aliases_prompt(&authors, &[]);
 	// Only interested in params passed to the mock (withf)
 }
 
@@ -91,6 +299,50 @@ fn prompt_for_aliases_using_fzf() {
 	// Only interested in params passed to the mock (withf)
 }
 
+#[test]
+fn pick_a_single_template_using_fzf() {
+	let mut runner = MockRunner::new();
+	runner
+		.expect_attach()
+		.withf(|cmd, args| cmd == "fzf" && args.is_empty())
+		.times(1)
+		.returning(|_, _| Err("irrelevant".into())); // This is done to avoid creating a Child
+	let cli = Cli::new(Box::new(MockInputReader::new()), Box::new(runner));
+
+	let _ = cli.template_prompt(&["hotfix".to_string()]);
+	// Only interested in params passed to the mock (withf)
+}
+
+#[test]
+fn prompt_for_a_template_variable() {
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline()
+		.withf(|prompt_msg| prompt_msg.contains("Enter scope"))
+		.times(1)
+		.returning(|_| Ok(" api ".to_string()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.variable_prompt("scope");
+
+	assert!(matches!(result, Ok(scope) if scope == "api"));
+}
+
+#[parameterized(cmd = { "notify-send", "notify-send -u critical" })]
+fn notify_with_the_given_command(cmd: &'static str) {
+	let mut runner = MockRunner::new();
+	runner
+		.expect_run_script()
+		.withf(move |script, args| script == format!("{cmd} \"$1\"") && args == ["a message".to_string()])
+		.times(1)
+		.returning(|_, _| Ok(()));
+	let cli = Cli::new(Box::new(MockInputReader::new()), Box::new(runner));
+
+	let result = cli.notify(cmd, "a message");
+
+	assert!(result.is_ok());
+}
+
 fn contains_in_order(prompt_msg: &str, components: &[&str]) -> bool {
 	// This is functionally equal to doing prompt_msg.contains("⦔ alias -> name")
 	// but only considers presence and relative order, discarding "colored" formatting (bold, colors, etc.)
@@ -99,3 +351,45 @@ fn contains_in_order(prompt_msg: &str, components: &[&str]) -> bool {
 	let are_in_order = found.windows(2).all(|comp| comp[0] < comp[1]);
 	are_contained && are_in_order
 }
+
+fn preview() -> Preview {
+	Preview {
+		message: CommitMessage::from("a message"),
+		author: Identity::from("an author", "an@author"),
+		committer: Identity::from("a committer", "a@committer"),
+		staged: vec!["modified:   foo".to_string()],
+		amend: false,
+	}
+}
+
+#[parameterized(
+	answer = { "", "y", "M", "c", "a" },
+	expected = { Decision::Commit, Decision::Commit, Decision::EditMessage, Decision::EditCoAuthors, Decision::Abort }
+)]
+fn decide_what_to_do_with_the_preview(answer: &'static str, expected: Decision) {
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline()
+		.times(1)
+		.returning(move |_| Ok(answer.to_string()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.preview_prompt(&preview());
+
+	assert!(matches!(result, Ok(decision) if decision == expected));
+}
+
+#[test]
+fn ask_again_when_the_answer_to_the_preview_is_unknown() {
+	let mut answers = vec!["x".to_string(), "m".to_string()].into_iter();
+	let mut reader = MockInputReader::new();
+	reader
+		.expect_readline()
+		.times(2)
+		.returning(move |_| Ok(answers.next().unwrap_or_default()));
+	let mut cli = Cli::new(Box::new(reader), Box::new(MockRunner::new()));
+
+	let result = cli.preview_prompt(&preview());
+
+	assert!(matches!(result, Ok(Decision::EditMessage)));
+}
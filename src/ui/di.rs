@@ -1,9 +1,44 @@
-use super::{cli::Cli, err::UiError};
-use crate::{common::runner::CommandRunner, Result};
+use super::{cli::Cli, err::UiError, helper::PromptHelper, input_reader::LineReader};
+use crate::{
+	common::{conf, env, runner::CommandRunner},
+	Result,
+};
+use git2::Repository;
+use rustyline::{history::DefaultHistory, Config, Editor};
+use std::path::PathBuf;
 
 pub fn init() -> Result<Cli> {
+	let to_ui_error = |e: rustyline::error::ReadlineError| UiError::Unknown(e.to_string());
+	let config = Config::builder()
+		.max_history_size(conf::history_size())
+		.map_err(to_ui_error)?
+		.history_ignore_dups(true)
+		.map_err(to_ui_error)?
+		.build();
+	let mut editor = Editor::<PromptHelper, DefaultHistory>::with_config(config).map_err(to_ui_error)?;
+	let types = if conf::conventional_commits() {
+		conf::conventional_types()
+	} else {
+		Vec::new()
+	};
+	editor.set_helper(Some(PromptHelper::new(conf::lint_subject_max_length(), types)));
 	Ok(Cli::new(
-		Box::new(rustyline::DefaultEditor::new().map_err(|e| UiError::Unknown(e.to_string()))?),
+		Box::new(LineReader::new(editor, history_dir())),
 		Box::new(CommandRunner),
 	))
 }
+
+/// In the git dir of the current repo, or the XDG state dir for every repo
+fn history_dir() -> Option<PathBuf> {
+	match conf::history().as_str() {
+		"repo" => env::cwd()
+			.ok()
+			.and_then(|cwd| Repository::discover(cwd).ok())
+			.map(|repo| repo.path().join(conf::authors_dir())),
+		"global" => env::xdg_state()
+			.or_else(|_| env::home().map(|home| format!("{home}/.local/state")))
+			.ok()
+			.map(|state_dir| PathBuf::from(state_dir).join(conf::authors_dir())),
+		_ => None,
+	}
+}
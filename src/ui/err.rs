@@ -5,6 +5,7 @@ use std::{any::Any, fmt::Display, io};
 pub enum UiError {
 	Io(io::Error),
 	Interrupted,
+	Aborted,
 	Unknown(String),
 	Fzf(String),
 }
@@ -23,6 +24,7 @@ impl PartialEq for UiError {
 			(self, other),
 			(UiError::Io(_), UiError::Io(_))
 				| (UiError::Interrupted, UiError::Interrupted)
+				| (UiError::Aborted, UiError::Aborted)
 				| (UiError::Unknown(_), UiError::Unknown(_))
 				| (UiError::Fzf(_), UiError::Fzf(_))
 		)
@@ -35,6 +37,7 @@ impl Display for UiError {
 		match self {
 			UiError::Io(ref err) => err.fmt(f),
 			UiError::Interrupted => write!(f, "Interrupted"),
+			UiError::Aborted => write!(f, "Commit aborted"),
 			UiError::Unknown(err) => write!(f, "{err}"),
 			UiError::Fzf(err) => write!(f, "fzf failed -> {err}"),
 		}
@@ -49,6 +52,7 @@ mod tests {
 	#[test]
 	fn test_ui_error_display() {
 		assert_eq!(format!("{}", UiError::Interrupted), "Cli: Interrupted");
+		assert_eq!(format!("{}", UiError::Aborted), "Cli: Commit aborted");
 		assert_eq!(format!("{}", UiError::Unknown("oops".to_string())), "Cli: oops");
 		assert_eq!(
 			format!("{}", UiError::Fzf("fzf oops".to_string())),
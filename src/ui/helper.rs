@@ -0,0 +1,144 @@
+use colored::Colorize;
+use rustyline::completion::Completer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::{Hint, Hinter};
+use rustyline::history::SearchDirection;
+use rustyline::validate::Validator;
+use rustyline::{Context, Helper};
+use std::borrow::Cow;
+use std::cell::Cell;
+
+/// What is being typed, for the hints and completions to follow
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Mode {
+	#[default]
+	Plain,
+	Subject,
+	Aliases,
+}
+
+/// Hints from the history and completes conventional types or aliases.
+/// While typing a subject with nothing to suggest, shows its length against the configured limit if any.
+#[derive(Debug, Default)]
+pub struct PromptHelper {
+	pub mode: Mode,
+	pub aliases: Vec<String>,
+	subject_limit: usize,
+	types: Vec<String>,
+	/// Whether the subject last hinted at is over the limit, for its counter to show in red
+	over_limit: Cell<bool>,
+}
+
+pub struct PromptHint {
+	text: String,
+	completion: bool,
+}
+
+impl Hint for PromptHint {
+	fn display(&self) -> &str {
+		&self.text
+	}
+
+	fn completion(&self) -> Option<&str> {
+		self.completion.then_some(self.text.as_str())
+	}
+}
+
+impl PromptHelper {
+	pub fn new(subject_limit: usize, types: Vec<String>) -> Self {
+		Self {
+			subject_limit,
+			types,
+			..Self::default()
+		}
+	}
+
+	/// Where the word being typed starts, and the candidates to complete it
+	pub fn candidates(&self, line: &str, pos: usize) -> (usize, Vec<String>) {
+		let typed = &line[..pos];
+		let (start, candidates) = match self.mode {
+			Mode::Plain => return (pos, Vec::new()),
+			// Only the type, the first word, is completed
+			Mode::Subject if typed.contains(char::is_whitespace) => return (pos, Vec::new()),
+			Mode::Subject => (0, &self.types),
+			// Aliases may be qualified with a role, as in `r:bb`
+			Mode::Aliases => (typed.rfind([' ', ':']).map_or(0, |index| index + 1), &self.aliases),
+		};
+		let word = &typed[start..];
+		let matching = candidates
+			.iter()
+			.filter(|candidate| candidate.starts_with(word))
+			.cloned()
+			.collect();
+		(start, matching)
+	}
+
+	fn counter(&self, line: &str) -> String {
+		let length = line.chars().count();
+		if self.subject_limit == 0 {
+			format!("  ({length})")
+		} else {
+			format!("  ({length}/{})", self.subject_limit)
+		}
+	}
+}
+
+impl Hinter for PromptHelper {
+	type Hint = PromptHint;
+
+	fn hint(&self, line: &str, pos: usize, ctx: &Context<'_>) -> Option<PromptHint> {
+		self.over_limit.set(false);
+		if self.mode == Mode::Plain || pos < line.len() {
+			return None;
+		}
+		// The most recent entry starting with what is typed
+		let recent = (!line.is_empty())
+			.then(|| {
+				let history = ctx.history();
+				history
+					.starts_with(line, history.len().saturating_sub(1), SearchDirection::Reverse)
+					.ok()
+					.flatten()
+			})
+			.flatten()
+			.filter(|found| found.entry.len() > line.len())
+			.map(|found| PromptHint {
+				text: found.entry[line.len()..].to_string(),
+				completion: true,
+			});
+		match self.mode {
+			Mode::Subject => recent.or_else(|| {
+				let length = line.chars().count();
+				self.over_limit
+					.set(self.subject_limit > 0 && length > self.subject_limit);
+				Some(PromptHint {
+					text: self.counter(line),
+					completion: false,
+				})
+			}),
+			_ => recent,
+		}
+	}
+}
+
+impl Highlighter for PromptHelper {
+	fn highlight_hint<'h>(&self, hint: &'h str) -> Cow<'h, str> {
+		if self.over_limit.get() {
+			Cow::Owned(hint.red().to_string())
+		} else {
+			Cow::Owned(hint.dimmed().to_string())
+		}
+	}
+}
+
+impl Completer for PromptHelper {
+	type Candidate = String;
+
+	fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+		Ok(self.candidates(line, pos))
+	}
+}
+
+impl Validator for PromptHelper {}
+
+impl Helper for PromptHelper {}
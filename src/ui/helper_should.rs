@@ -0,0 +1,82 @@
+use super::helper::{Mode, PromptHelper};
+use colored::Colorize;
+use parameterized::parameterized;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::{Hint, Hinter};
+use rustyline::history::{DefaultHistory, History};
+use rustyline::Context;
+
+fn types() -> Vec<String> {
+	vec!["feat".to_string(), "fix".to_string(), "docs".to_string()]
+}
+
+#[test]
+fn complete_the_conventional_type_of_a_subject() {
+	let mut helper = PromptHelper::new(0, types());
+	helper.mode = Mode::Subject;
+
+	assert_eq!(
+		helper.candidates("f", 1),
+		(0, vec!["feat".to_string(), "fix".to_string()])
+	);
+	assert_eq!(helper.candidates("fix: f", 6), (6, vec![]));
+}
+
+#[test]
+fn complete_the_alias_being_typed_role_or_not() {
+	let mut helper = PromptHelper::new(0, types());
+	helper.mode = Mode::Aliases;
+	helper.aliases = vec!["aj".to_string(), "bb".to_string(), "bo".to_string()];
+
+	assert_eq!(
+		helper.candidates("aj b", 4),
+		(3, vec!["bb".to_string(), "bo".to_string()])
+	);
+	assert_eq!(helper.candidates("aj r:a", 6), (5, vec!["aj".to_string()]));
+}
+
+#[test]
+fn complete_nothing_in_other_prompts() {
+	let helper = PromptHelper::new(0, types());
+
+	assert_eq!(helper.candidates("f", 1), (1, vec![]));
+}
+
+#[test]
+fn hint_the_most_recent_matching_entry() {
+	let mut history = DefaultHistory::new();
+	history.add("fix the bug").ok();
+	history.add("fix the build").ok();
+	let mut helper = PromptHelper::new(0, types());
+	helper.mode = Mode::Subject;
+
+	let hint = helper.hint("fix the b", 9, &Context::new(&history));
+
+	assert_eq!(hint.as_ref().map(Hint::display), Some("uild"));
+	assert_eq!(hint.as_ref().and_then(Hint::completion), Some("uild"));
+}
+
+#[test]
+fn count_the_subject_length_when_nothing_to_hint() {
+	let history = DefaultHistory::new();
+	let mut helper = PromptHelper::new(50, types());
+	helper.mode = Mode::Subject;
+
+	let hint = helper.hint("a subject", 9, &Context::new(&history));
+
+	assert_eq!(hint.as_ref().map(Hint::display), Some("  (9/50)"));
+	assert_eq!(hint.as_ref().and_then(Hint::completion), None);
+}
+
+#[parameterized(subject = { "a subject", "a subject over" }, over = { false, true })]
+fn show_the_count_in_red_once_over_the_limit(subject: &str, over: bool) {
+	let history = DefaultHistory::new();
+	let mut helper = PromptHelper::new(10, types());
+	helper.mode = Mode::Subject;
+
+	let hint = helper.hint(subject, subject.len(), &Context::new(&history));
+	let counter = hint.as_ref().map(Hint::display).unwrap_or_default();
+
+	let expected = if over { counter.red() } else { counter.dimmed() };
+	assert_eq!(helper.highlight_hint(counter), expected.to_string());
+}
@@ -1,29 +1,118 @@
 use super::err::UiError;
+use super::helper::{Mode, PromptHelper};
 use crate::Result;
-use rustyline::{error::ReadlineError, DefaultEditor};
+use rustyline::{error::ReadlineError, history::DefaultHistory, Editor};
+use std::path::PathBuf;
 
 #[cfg_attr(test, mockall::automock)]
 pub trait InputReader {
 	fn readline(&mut self, prompt: &str) -> Result<String>;
 	fn readline_with_prompt<'a>(&mut self, prompt: &str, initial: (&'a str, &'a str)) -> Result<String>;
+	/// Like `readline_with_prompt`, showing the length of the line as it is typed
+	fn readline_subject<'a>(&mut self, prompt: &str, initial: (&'a str, &'a str)) -> Result<String>;
+	/// Like `readline_with_prompt`, `None` meaning the input was ended (Ctrl-D)
+	fn readline_until_end<'a>(&mut self, prompt: &str, initial: (&'a str, &'a str)) -> Result<Option<String>>;
+	/// Like `readline_with_prompt`, completing the given aliases
+	fn readline_aliases<'a>(&mut self, prompt: &str, initial: (&'a str, &'a str), aliases: &[String])
+		-> Result<String>;
 }
 
-impl InputReader for DefaultEditor {
+/// Subjects and aliases are remembered across runs, each in their own history file
+pub struct LineReader {
+	editor: Editor<PromptHelper, DefaultHistory>,
+	history_dir: Option<PathBuf>,
+}
+
+const SUBJECTS_HISTORY: &str = "subjects_history";
+const ALIASES_HISTORY: &str = "aliases_history";
+
+impl InputReader for LineReader {
 	fn readline(&mut self, prompt: &str) -> Result<String> {
-		Ok(self.readline(&format!("{prompt}> ")).map_err(|e| match e {
-			ReadlineError::Interrupted => UiError::Interrupted,
-			ReadlineError::Io(e) => UiError::Io(e),
-			e => UiError::Unknown(e.to_string()),
-		})?)
+		self.readline_with_prompt(prompt, ("", ""))
 	}
 
 	fn readline_with_prompt(&mut self, prompt: &str, pre_populate: (&str, &str)) -> Result<String> {
+		self.switch_to(Mode::Plain, None)?;
+		self.read(prompt, pre_populate)
+	}
+
+	fn readline_subject(&mut self, prompt: &str, pre_populate: (&str, &str)) -> Result<String> {
+		self.switch_to(Mode::Subject, Some(SUBJECTS_HISTORY))?;
+		let input = self.read(prompt, pre_populate)?;
+		self.remember(&input, SUBJECTS_HISTORY);
+		Ok(input)
+	}
+
+	fn readline_until_end(&mut self, prompt: &str, pre_populate: (&str, &str)) -> Result<Option<String>> {
+		self.switch_to(Mode::Plain, None)?;
+		match self.editor.readline_with_initial(&format!("{prompt}> "), pre_populate) {
+			Ok(line) => Ok(Some(line)),
+			Err(ReadlineError::Eof) => Ok(None),
+			Err(e) => Err(to_ui_error(e).into()),
+		}
+	}
+
+	fn readline_aliases(&mut self, prompt: &str, pre_populate: (&str, &str), aliases: &[String]) -> Result<String> {
+		self.switch_to(Mode::Aliases, Some(ALIASES_HISTORY))?;
+		if let Some(helper) = self.editor.helper_mut() {
+			helper.aliases = aliases.to_vec();
+		}
+		let input = self.read(prompt, pre_populate)?;
+		self.remember(&input, ALIASES_HISTORY);
+		Ok(input)
+	}
+}
+
+impl LineReader {
+	/// Without a history dir nothing is remembered
+	pub fn new(editor: Editor<PromptHelper, DefaultHistory>, history_dir: Option<PathBuf>) -> Self {
+		Self { editor, history_dir }
+	}
+
+	fn read(&mut self, prompt: &str, pre_populate: (&str, &str)) -> Result<String> {
 		Ok(self
+			.editor
 			.readline_with_initial(&format!("{prompt}> "), pre_populate)
-			.map_err(|e| match e {
-				ReadlineError::Interrupted => UiError::Interrupted,
-				ReadlineError::Io(e) => UiError::Io(e),
-				e => UiError::Unknown(e.to_string()),
-			})?)
+			.map_err(to_ui_error)?)
+	}
+
+	fn switch_to(&mut self, mode: Mode, history: Option<&str>) -> Result<()> {
+		if let Some(helper) = self.editor.helper_mut() {
+			helper.mode = mode;
+		}
+		self.editor.clear_history().map_err(to_ui_error)?;
+		if let Some(path) = self.history_path(history) {
+			// Nothing remembered yet
+			if path.exists() {
+				self.editor.load_history(&path).map_err(to_ui_error)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Failing to remember doesn't fail the commit
+	fn remember(&mut self, input: &str, history: &str) {
+		let Some(path) = self.history_path(Some(history)) else {
+			return;
+		};
+		if input.trim().is_empty() || self.editor.add_history_entry(input.trim()).is_err() {
+			return;
+		}
+		if let Some(dir) = path.parent() {
+			std::fs::create_dir_all(dir).ok();
+		}
+		self.editor.append_history(&path).ok();
+	}
+
+	fn history_path(&self, history: Option<&str>) -> Option<PathBuf> {
+		Some(self.history_dir.as_ref()?.join(history?))
+	}
+}
+
+fn to_ui_error(e: ReadlineError) -> UiError {
+	match e {
+		ReadlineError::Interrupted => UiError::Interrupted,
+		ReadlineError::Io(e) => UiError::Io(e),
+		e => UiError::Unknown(e.to_string()),
 	}
 }
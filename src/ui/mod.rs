@@ -1,7 +1,10 @@
 pub mod cli;
 pub mod di;
 pub mod err;
+pub mod helper;
 pub mod input_reader;
 
 #[cfg(test)]
 mod cli_should;
+#[cfg(test)]
+mod helper_should;